@@ -0,0 +1,260 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use rbt_core::export::animation::{self, AnimationFormat, AnimationOptions};
+use rbt_core::export::tikz::{self, Package};
+use rbt_core::export::{dot, script, svg};
+use rbt_core::scene::SceneOptions;
+use rbt_core::trace::{Operation, Trace};
+use rbt_core::tree::{Duplicates, RBTree};
+
+const USAGE: &str =
+    "Usage: rbt-render [SCRIPT] [--out DIR] [--summary] [--nil] [--size] [--sum] [--min-max]
+                   [--duplicates POLICY]
+                   [--format FORMAT] [--animate FILE [--delay MS] [--from N] [--to N]]
+
+Runs an operation script through the red-black tree and writes one frame per recorded step.
+Reads the script from stdin when SCRIPT is omitted or `-`.
+
+Script format, one command per line (`#` starts a comment):
+  insert 10 20 30
+  delete 20
+  search 30
+
+Options:
+  -o, --out DIR    directory for the frames (default: frames)
+  -s, --summary    print rotations and recolorings per operation
+      --nil        draw NIL leaves and label nodes with their black height
+      --size       label nodes with their subtree size
+      --sum        label nodes with the sum of the keys in their subtree
+      --min-max    label nodes with the smallest and largest key in their subtree
+      --duplicates POLICY
+                   reject (default), count or multiset
+  -f, --format FORMAT
+                   svg (default), dot, forest or qtree (TikZ)
+  -a, --animate FILE
+                   write one animated GIF (.gif) or APNG (.png, .apng) of the steps
+                   instead of separate frames
+      --delay MS   time each step stays on screen in the animation (default: 600)
+      --from N, --to N
+                   animate only steps N to M, counting from 1 as the frame
+                   names and the app's timeline do (inclusive)
+  -h, --help       show this message";
+
+#[derive(Copy, Clone)]
+enum Format {
+    Svg,
+    Dot,
+    Tikz(Package),
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Svg => "svg",
+            Format::Dot => "dot",
+            Format::Tikz(_) => "tex",
+        }
+    }
+
+    fn render(self, tree: &RBTree, options: SceneOptions) -> String {
+        match self {
+            Format::Svg => svg::to_svg_with(tree, options),
+            Format::Dot => dot::to_dot_with(tree, options),
+            Format::Tikz(package) => tikz::to_tikz(tree, package, options),
+        }
+    }
+}
+
+struct Args {
+    script: Option<PathBuf>,
+    out: PathBuf,
+    summary: bool,
+    options: SceneOptions,
+    duplicates: Duplicates,
+    format: Format,
+    animate: Option<PathBuf>,
+    animation: AnimationOptions,
+    from: usize,
+    to: Option<usize>,
+}
+
+// `None` when the user asked for help.
+fn parse_args() -> Result<Option<Args>, String> {
+    let mut args = Args {
+        script: None,
+        out: PathBuf::from("frames"),
+        summary: false,
+        options: SceneOptions::default(),
+        duplicates: Duplicates::default(),
+        format: Format::Svg,
+        animate: None,
+        animation: AnimationOptions::default(),
+        from: 1,
+        to: None,
+    };
+
+    let mut argv = std::env::args().skip(1);
+    while let Some(arg) = argv.next() {
+        match arg.as_str() {
+            "-o" | "--out" => {
+                let dir = argv.next().ok_or("--out needs a directory")?;
+                args.out = PathBuf::from(dir);
+            }
+            "-s" | "--summary" => args.summary = true,
+            "--nil" => {
+                args.options.nil_leaves = true;
+                args.options.black_height = true;
+            }
+            "--size" => args.options.size = true,
+            "--sum" => args.options.sum = true,
+            "--min-max" => args.options.min_max = true,
+            "--duplicates" => {
+                args.duplicates = match argv.next().as_deref() {
+                    Some("reject") => Duplicates::Reject,
+                    Some("count") => Duplicates::Count,
+                    Some("multiset") => Duplicates::Multiset,
+                    _ => return Err("--duplicates needs reject, count or multiset".to_string()),
+                };
+            }
+            "-f" | "--format" => {
+                args.format = match argv.next().as_deref() {
+                    Some("svg") => Format::Svg,
+                    Some("dot") => Format::Dot,
+                    Some("forest") => Format::Tikz(Package::Forest),
+                    Some("qtree") => Format::Tikz(Package::Qtree),
+                    _ => return Err("--format needs svg, dot, forest or qtree".to_string()),
+                };
+            }
+            "-a" | "--animate" => {
+                let path = PathBuf::from(argv.next().ok_or("--animate needs a file")?);
+                args.animation.format = match path.extension().and_then(|ext| ext.to_str()) {
+                    Some("gif") => AnimationFormat::Gif,
+                    Some("png" | "apng") => AnimationFormat::Apng,
+                    _ => return Err("--animate needs a .gif, .png or .apng file".to_string()),
+                };
+                args.animate = Some(path);
+            }
+            "--delay" => args.animation.delay_ms = number(argv.next(), "--delay")?,
+            "--from" => args.from = number(argv.next(), "--from")?,
+            "--to" => args.to = Some(number(argv.next(), "--to")?),
+            "-h" | "--help" => return Ok(None),
+            "-" => args.script = None,
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            _ => args.script = Some(PathBuf::from(arg)),
+        }
+    }
+
+    Ok(Some(args))
+}
+
+fn number<T: std::str::FromStr>(value: Option<String>, option: &str) -> Result<T, String> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("{option} needs a non-negative number"))
+}
+
+fn read_script(path: &Option<PathBuf>) -> io::Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+fn describe(operation: Operation) -> String {
+    match operation {
+        Operation::Insert(key) => format!("insert {key}"),
+        Operation::Delete(key) => format!("delete {key}"),
+        Operation::Search(key) => format!("search {key}"),
+    }
+}
+
+fn print_summary(traces: &[Trace]) {
+    let (mut rotations, mut recolorings) = (0, 0);
+    for trace in traces {
+        println!(
+            "{:<16} {:>3} steps {:>3} rotations {:>3} recolorings",
+            describe(trace.operation),
+            trace.steps.len(),
+            trace.rotations(),
+            trace.recolorings()
+        );
+        rotations += trace.rotations();
+        recolorings += trace.recolorings();
+    }
+    println!("total: {rotations} rotations, {recolorings} recolorings");
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let source = read_script(&args.script).map_err(|err| format!("cannot read script: {err}"))?;
+    let operations = script::parse(&source).map_err(|err| err.to_string())?;
+
+    let mut tree = RBTree::with_duplicates(args.duplicates);
+    let traces: Vec<Trace> = operations
+        .into_iter()
+        .map(|operation| tree.apply_traced(operation))
+        .collect();
+    let steps = traces.iter().flat_map(|trace| &trace.steps);
+
+    if let Some(path) = &args.animate {
+        let to = args.to.unwrap_or(usize::MAX);
+        let trees = steps
+            .zip(1..)
+            .filter(|(_, frame)| (args.from..=to).contains(frame))
+            .map(|(step, _)| &step.tree);
+        let options = AnimationOptions {
+            scene: args.options,
+            ..args.animation
+        };
+        let bytes = animation::encode(trees, options).map_err(|err| err.to_string())?;
+        fs::write(path, bytes).map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+        eprintln!("wrote {}", path.display());
+    } else {
+        fs::create_dir_all(&args.out)
+            .map_err(|err| format!("cannot create {}: {err}", args.out.display()))?;
+        let mut frames = 0;
+        for (step, frame) in steps.zip(1..) {
+            let path = args
+                .out
+                .join(format!("step-{frame:04}.{}", args.format.extension()));
+            fs::write(&path, args.format.render(&step.tree, args.options))
+                .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+            frames = frame;
+        }
+        eprintln!("wrote {frames} frames to {}", args.out.display());
+    }
+
+    if args.summary {
+        print_summary(&traces);
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::OnceLock;
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rbt_core::export::svg;
+use rbt_core::{Operation, RBTree, Scene, TreeLayout};
+
+const SIZES: [u32; 3] = [1_000, 10_000, 100_000];
+
+// Multiplying by an odd constant permutes u32, so keys are distinct but unsorted.
+fn key(i: u32) -> i32 {
+    i.wrapping_mul(2_654_435_761) as i32
+}
+
+// Each size is built once and shared between groups, so setting up the 100k tree is not
+// repeated for every group that measures it.
+fn trees() -> &'static [(u32, RBTree)] {
+    static TREES: OnceLock<Vec<(u32, RBTree)>> = OnceLock::new();
+    TREES.get_or_init(|| {
+        SIZES
+            .iter()
+            .map(|&size| {
+                let mut tree = RBTree::new();
+                for i in 0..size {
+                    tree.insert(key(i));
+                }
+                (size, tree)
+            })
+            .collect()
+    })
+}
+
+fn operations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("operations");
+    group.sample_size(20);
+
+    for (size, tree) in trees() {
+        let size = *size;
+
+        group.bench_with_input(BenchmarkId::new("insert", size), tree, |b, tree| {
+            b.iter_batched(
+                || tree.clone(),
+                |mut tree| tree.insert(key(size)),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("delete", size), tree, |b, tree| {
+            b.iter_batched(
+                || tree.clone(),
+                |mut tree| tree.delete(key(size / 2)),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("search", size), tree, |b, tree| {
+            b.iter(|| tree.search(key(size / 2)))
+        });
+    }
+
+    group.finish();
+}
+
+// The app pushes a copy of the tree into `TREE_STATES` for every recorded step.
+fn history(c: &mut Criterion) {
+    let mut group = c.benchmark_group("history");
+    group.sample_size(20);
+
+    for (size, tree) in trees() {
+        let size = *size;
+
+        group.bench_with_input(BenchmarkId::new("snapshot", size), tree, |b, tree| {
+            b.iter(|| tree.snapshot())
+        });
+        group.bench_with_input(BenchmarkId::new("traced_insert", size), tree, |b, tree| {
+            b.iter_batched(
+                || tree.clone(),
+                |mut tree| tree.apply_traced(Operation::Insert(key(size))),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+fn rendering(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rendering");
+    group.sample_size(20);
+
+    for (size, tree) in trees() {
+        let size = *size;
+        let layout = TreeLayout::new(tree);
+
+        group.bench_with_input(BenchmarkId::new("layout", size), tree, |b, tree| {
+            b.iter(|| TreeLayout::new(tree))
+        });
+        group.bench_with_input(BenchmarkId::new("scene", size), tree, |b, tree| {
+            b.iter(|| Scene::new(tree, &layout))
+        });
+        group.bench_with_input(BenchmarkId::new("svg", size), tree, |b, tree| {
+            b.iter(|| svg::to_svg(tree))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, operations, history, rendering);
+criterion_main!(benches);
@@ -0,0 +1,194 @@
+use std::fmt;
+
+use crate::tree::{Node, Pointer, RBTree};
+
+/// A value summarising a subtree, computed from a node's own contribution and its children's
+/// values. A tree carries the augmentation named by its type parameter, e.g. `RBTree<Sum>`;
+/// tuples carry several at once and `()` none. Its value is stored on every node and recomputed
+/// for just the nodes an operation touches: the path above an inserted or removed node, and the
+/// two nodes of a rotation plus any ancestors whose value changes with them.
+///
+/// Every tree also keeps [`Size`] in `Node::size` by itself, since `len`, `rank` and the layout
+/// depend on it.
+pub trait Augmentation {
+    type Value: Copy + Default + PartialEq + fmt::Debug;
+
+    /// The value for a node with no children.
+    fn own<V>(node: &Node<V>) -> Self::Value;
+    fn combine(
+        own: Self::Value,
+        left: Option<Self::Value>,
+        right: Option<Self::Value>,
+    ) -> Self::Value;
+}
+
+/// An augmentation whose values contain `A`'s, so [`RBTree::aggregate`] can pick it out.
+pub trait Includes<A: Augmentation>: Augmentation {
+    fn part(value: Self::Value) -> A::Value;
+}
+
+/// What the visualiser's trees carry.
+pub type Standard = (Sum, Min, Max);
+
+/// Keys in the subtree, counting each node's copies.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Size;
+
+/// Sum of the keys in the subtree, counting each node's copies.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Sum;
+
+/// Smallest key in the subtree.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Min;
+
+/// Largest `high` in the subtree: the interval-tree max, and the largest key for plain keys.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Max;
+
+impl Augmentation for Size {
+    type Value = u32;
+
+    fn own<V>(node: &Node<V>) -> u32 {
+        node.count
+    }
+
+    fn combine(own: u32, left: Option<u32>, right: Option<u32>) -> u32 {
+        own + left.unwrap_or(0) + right.unwrap_or(0)
+    }
+}
+
+impl Augmentation for Sum {
+    type Value = i64;
+
+    fn own<V>(node: &Node<V>) -> i64 {
+        i64::from(node.value) * i64::from(node.count)
+    }
+
+    fn combine(own: i64, left: Option<i64>, right: Option<i64>) -> i64 {
+        own + left.unwrap_or(0) + right.unwrap_or(0)
+    }
+}
+
+impl Augmentation for Min {
+    type Value = i32;
+
+    fn own<V>(node: &Node<V>) -> i32 {
+        node.value
+    }
+
+    fn combine(own: i32, left: Option<i32>, right: Option<i32>) -> i32 {
+        [left, right].into_iter().flatten().fold(own, i32::min)
+    }
+}
+
+impl Augmentation for Max {
+    type Value = i32;
+
+    fn own<V>(node: &Node<V>) -> i32 {
+        node.high
+    }
+
+    fn combine(own: i32, left: Option<i32>, right: Option<i32>) -> i32 {
+        [left, right].into_iter().flatten().fold(own, i32::max)
+    }
+}
+
+impl Augmentation for () {
+    type Value = ();
+
+    fn own<V>(_: &Node<V>) {}
+
+    fn combine(_: (), _: Option<()>, _: Option<()>) {}
+}
+
+impl<A: Augmentation, B: Augmentation> Augmentation for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn own<V>(node: &Node<V>) -> Self::Value {
+        (A::own(node), B::own(node))
+    }
+
+    fn combine(
+        own: Self::Value,
+        left: Option<Self::Value>,
+        right: Option<Self::Value>,
+    ) -> Self::Value {
+        (
+            A::combine(own.0, left.map(|v| v.0), right.map(|v| v.0)),
+            B::combine(own.1, left.map(|v| v.1), right.map(|v| v.1)),
+        )
+    }
+}
+
+impl<A: Augmentation, B: Augmentation, C: Augmentation> Augmentation for (A, B, C) {
+    type Value = (A::Value, B::Value, C::Value);
+
+    fn own<V>(node: &Node<V>) -> Self::Value {
+        (A::own(node), B::own(node), C::own(node))
+    }
+
+    fn combine(
+        own: Self::Value,
+        left: Option<Self::Value>,
+        right: Option<Self::Value>,
+    ) -> Self::Value {
+        (
+            A::combine(own.0, left.map(|v| v.0), right.map(|v| v.0)),
+            B::combine(own.1, left.map(|v| v.1), right.map(|v| v.1)),
+            C::combine(own.2, left.map(|v| v.2), right.map(|v| v.2)),
+        )
+    }
+}
+
+impl<A: Augmentation> Includes<A> for A {
+    fn part(value: A::Value) -> A::Value {
+        value
+    }
+}
+
+impl Includes<Sum> for Standard {
+    fn part(value: Self::Value) -> i64 {
+        value.0
+    }
+}
+
+impl Includes<Min> for Standard {
+    fn part(value: Self::Value) -> i32 {
+        value.1
+    }
+}
+
+impl Includes<Max> for Standard {
+    fn part(value: Self::Value) -> i32 {
+        value.2
+    }
+}
+
+impl<A: Augmentation> RBTree<A> {
+    /// `P`'s stored value for the subtree rooted at `node`.
+    pub fn aggregate<P: Augmentation>(&self, node: Pointer) -> P::Value
+    where
+        A: Includes<P>,
+    {
+        A::part(self[node].aggregate)
+    }
+
+    /// The tree's augmentation for `node` computed from its children's stored values.
+    pub fn recompute(&self, node: Pointer) -> A::Value {
+        let child = |child: Pointer| (!child.is_null()).then(|| self[child].aggregate);
+        let current = &self[node];
+        A::combine(A::own(current), child(current.left), child(current.right))
+    }
+
+    // Subtree size of `node` computed from its children's stored sizes.
+    pub(crate) fn recompute_size(&self, node: Pointer) -> u32 {
+        let child = |child: Pointer| (!child.is_null()).then(|| self[child].size);
+        let current = &self[node];
+        Size::combine(
+            Size::own(current),
+            child(current.left),
+            child(current.right),
+        )
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use crate::augment::Augmentation;
+use crate::tree::{Color, Node, Pointer, RBTree};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EditError {
+    /// The target already has a child on that side.
+    Occupied,
+    /// The target is inside the subtree being moved.
+    Cycle,
+    /// The key is already in the tree.
+    Duplicate,
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EditError::Occupied => write!(f, "that child slot is already taken"),
+            EditError::Cycle => write!(f, "a subtree cannot be moved into itself"),
+            EditError::Duplicate => write!(f, "the key is already in the tree"),
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+// Manual edits for practising fixups by hand. Unlike `insert` and `delete` they make no attempt
+// to keep the tree valid; check the result with `violations`.
+impl<A: Augmentation> RBTree<A> {
+    pub fn toggle_color(&mut self, node: Pointer) {
+        self[node].color = match self[node].color {
+            Color::Red => Color::Black,
+            Color::Black => Color::Red,
+        };
+    }
+
+    /// Attaches `val` as a red leaf where a search for it ends, leaving the fixup to the caller.
+    pub fn attach_leaf(&mut self, val: i32) -> Result<Pointer, EditError> {
+        let mut parent = Pointer::null();
+        let mut current = self.root;
+        while !current.is_null() {
+            if self[current].value == val {
+                return Err(EditError::Duplicate);
+            }
+            parent = current;
+            current = if val < self[current].value {
+                self[current].left
+            } else {
+                self[current].right
+            };
+        }
+
+        let leaf = self.alloc(Node::new::<A>(val, val, parent, Color::Red));
+        if parent.is_null() {
+            self.root = leaf;
+        } else if val < self[parent].value {
+            self[parent].left = leaf;
+        } else {
+            self[parent].right = leaf;
+        }
+        self.update_path(parent);
+        Ok(leaf)
+    }
+
+    /// Cuts the subtree rooted at `node` off its parent and hangs it on `side` of `target`.
+    pub fn reattach(
+        &mut self,
+        node: Pointer,
+        target: Pointer,
+        side: Side,
+    ) -> Result<(), EditError> {
+        let slot = match side {
+            Side::Left => self[target].left,
+            Side::Right => self[target].right,
+        };
+        if !slot.is_null() {
+            return Err(EditError::Occupied);
+        }
+        let mut ancestor = target;
+        while !ancestor.is_null() {
+            if ancestor == node {
+                return Err(EditError::Cycle);
+            }
+            ancestor = self[ancestor].parent;
+        }
+
+        // `target` is outside the subtree, so `node` is not the root.
+        let parent = self[node].parent;
+        if self[parent].left == node {
+            self[parent].left = Pointer::null();
+        } else {
+            self[parent].right = Pointer::null();
+        }
+        self[node].parent = target;
+        match side {
+            Side::Left => self[target].left = node,
+            Side::Right => self[target].right = node,
+        }
+        self.update_path(parent);
+        self.update_path(target);
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+
+use crate::export::raster::{render_frame, Frame};
+use crate::layout::{Bounds, TreeLayout};
+use crate::scene::{frame_bounds, Scene, SceneOptions};
+use crate::tree::RBTree;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AnimationFormat {
+    #[default]
+    Gif,
+    Apng,
+}
+
+impl AnimationFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            AnimationFormat::Gif => "gif",
+            AnimationFormat::Apng => "png",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            AnimationFormat::Gif => "image/gif",
+            AnimationFormat::Apng => "image/apng",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AnimationOptions {
+    pub format: AnimationFormat,
+    /// How long each state stays on screen.
+    pub delay_ms: u32,
+    /// Extra time the final state is held before the animation loops.
+    pub hold_last_ms: u32,
+    /// Pixels per canvas unit, lowered if the frames would exceed `max_width`.
+    pub scale: f32,
+    pub max_width: u32,
+    pub scene: SceneOptions,
+}
+
+impl Default for AnimationOptions {
+    fn default() -> Self {
+        AnimationOptions {
+            format: AnimationFormat::Gif,
+            delay_ms: 600,
+            hold_last_ms: 1500,
+            scale: 4.0,
+            max_width: 1600,
+            scene: SceneOptions::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnimationError {
+    NoFrames,
+    TooLarge,
+    Gif(gif::EncodingError),
+    Png(png::EncodingError),
+}
+
+impl fmt::Display for AnimationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationError::NoFrames => write!(f, "there are no non-empty states to animate"),
+            AnimationError::TooLarge => write!(f, "the frames are too large to encode"),
+            AnimationError::Gif(err) => write!(f, "cannot encode GIF: {err}"),
+            AnimationError::Png(err) => write!(f, "cannot encode APNG: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for AnimationError {}
+
+impl From<gif::EncodingError> for AnimationError {
+    fn from(err: gif::EncodingError) -> Self {
+        AnimationError::Gif(err)
+    }
+}
+
+impl From<png::EncodingError> for AnimationError {
+    fn from(err: png::EncodingError) -> Self {
+        AnimationError::Png(err)
+    }
+}
+
+/// Renders every tree as one frame of a looping animation. Each tree is laid out on its own,
+/// but all frames share the canvas area that fits every state, so the picture keeps its size
+/// and scale; empty trees are skipped.
+pub fn encode<'a>(
+    trees: impl IntoIterator<Item = &'a RBTree>,
+    options: AnimationOptions,
+) -> Result<Vec<u8>, AnimationError> {
+    let trees: Vec<&RBTree> = trees
+        .into_iter()
+        .filter(|tree| !tree.root.is_null())
+        .collect();
+    let area = trees
+        .iter()
+        .filter_map(|tree| {
+            frame_bounds(&Scene::with_options(
+                tree,
+                &TreeLayout::new(tree),
+                options.scene,
+            ))
+        })
+        .reduce(Bounds::union)
+        .ok_or(AnimationError::NoFrames)?;
+    let scale = options
+        .scale
+        .min(options.max_width as f32 / area.width())
+        .max(f32::EPSILON);
+
+    let frames = trees
+        .iter()
+        .map(|tree| render_frame(tree, options.scene, area, scale).ok_or(AnimationError::TooLarge))
+        .collect::<Result<Vec<Frame>, _>>()?;
+    let delays: Vec<u32> = (0..frames.len())
+        .map(|i| {
+            if i + 1 == frames.len() {
+                options.delay_ms + options.hold_last_ms
+            } else {
+                options.delay_ms
+            }
+        })
+        .collect();
+
+    match options.format {
+        AnimationFormat::Gif => encode_gif(frames, &delays),
+        AnimationFormat::Apng => encode_apng(&frames, &delays),
+    }
+}
+
+fn encode_gif(frames: Vec<Frame>, delays: &[u32]) -> Result<Vec<u8>, AnimationError> {
+    let (width, height) = (frames[0].width, frames[0].height);
+    let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(AnimationError::TooLarge);
+    };
+
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut bytes, width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for (mut frame, delay) in frames.into_iter().zip(delays) {
+            let mut gif_frame = gif::Frame::from_rgba_speed(width, height, &mut frame.rgba, 10);
+            // GIF delays are in hundredths of a second.
+            gif_frame.delay = (delay / 10).min(u16::MAX as u32) as u16;
+            encoder.write_frame(&gif_frame)?;
+        }
+    }
+    Ok(bytes)
+}
+
+fn encode_apng(frames: &[Frame], delays: &[u32]) -> Result<Vec<u8>, AnimationError> {
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut bytes, frames[0].width, frames[0].height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_animated(frames.len() as u32, 0)?;
+        let mut writer = encoder.write_header()?;
+        for (frame, &delay) in frames.iter().zip(delays) {
+            writer.set_frame_delay(delay.min(u16::MAX as u32) as u16, 1000)?;
+            writer.write_image_data(&frame.rgba)?;
+        }
+        writer.finish()?;
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,81 @@
+use std::fmt::Write;
+
+use crate::scene::{fill, stroke, Scene, SceneOptions, EDGE_STROKE, NIL_FILL};
+use crate::tree::{Pointer, RBTree};
+
+/// Renders the tree as a Graphviz `digraph`, e.g. for `dot -Tpdf`.
+pub fn to_dot(tree: &RBTree) -> String {
+    to_dot_with(tree, SceneOptions::default())
+}
+
+/// Like [`to_dot`], with NIL leaves and black-height/size annotations as in the canvas.
+/// Annotations become `xlabel`s.
+pub fn to_dot_with(tree: &RBTree, options: SceneOptions) -> String {
+    let mut dot = String::new();
+    let _ = writeln!(dot, "digraph rbtree {{");
+    let _ = writeln!(
+        dot,
+        r#"  node [shape=circle, style=filled, fontcolor=white, fontname="Helvetica-Bold", width=0.4, fixedsize=true];"#
+    );
+    let _ = writeln!(dot, r#"  edge [color="{EDGE_STROKE}", arrowsize=0.6];"#);
+
+    let mut ids = 0;
+    if !tree.root.is_null() {
+        write_node(&mut dot, tree, options, tree.root, &mut ids);
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+// Writes the subtree at `pointer` and returns the id it was given.
+fn write_node(
+    dot: &mut String,
+    tree: &RBTree,
+    options: SceneOptions,
+    pointer: Pointer,
+    ids: &mut usize,
+) -> String {
+    let id = format!("n{ids}");
+    *ids += 1;
+
+    let node = &tree[pointer];
+    let mut label = node.value.to_string();
+    if node.count > 1 {
+        label.push_str(&format!("×{}", node.count));
+    }
+    let xlabel = Scene::annotation(tree, options, pointer)
+        .map(|annotation| format!(r#", xlabel="{annotation}""#))
+        .unwrap_or_default();
+    let _ = writeln!(
+        dot,
+        r#"  {id} [label="{label}", fillcolor="{}", color="{}"{xlabel}];"#,
+        fill(node.color),
+        stroke(node.color)
+    );
+
+    // Graphviz centres an only child, so a missing side gets an invisible stand-in to keep
+    // left and right apart.
+    let single = node.left.is_null() != node.right.is_null();
+    for child in [node.left, node.right] {
+        if !child.is_null() {
+            let child_id = write_node(dot, tree, options, child, ids);
+            let _ = writeln!(dot, "  {id} -> {child_id};");
+        } else if options.nil_leaves {
+            let nil_id = format!("n{ids}");
+            *ids += 1;
+            let _ = writeln!(
+                dot,
+                r#"  {nil_id} [shape=box, label="", width=0.15, height=0.15, fillcolor="{NIL_FILL}", color="{NIL_FILL}"];"#
+            );
+            let _ = writeln!(dot, "  {id} -> {nil_id} [arrowhead=none];");
+        } else if single {
+            let ghost_id = format!("n{ids}");
+            *ids += 1;
+            let _ = writeln!(dot, "  {ghost_id} [style=invis];");
+            let _ = writeln!(dot, "  {id} -> {ghost_id} [style=invis];");
+        }
+    }
+
+    id
+}
@@ -0,0 +1,37 @@
+use super::ImportError;
+use crate::trace::Operation;
+
+/// Encodes operations as a query-string-safe list such as `i5,i3,d5,s3`.
+pub fn encode(operations: &[Operation]) -> String {
+    operations
+        .iter()
+        .map(|operation| match operation {
+            Operation::Insert(key) => format!("i{key}"),
+            Operation::Delete(key) => format!("d{key}"),
+            Operation::Search(key) => format!("s{key}"),
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Parses the output of [`encode`], also accepting a percent-encoded separator.
+pub fn decode(input: &str) -> Result<Vec<Operation>, ImportError> {
+    let input = input.replace("%2C", ",").replace("%2c", ",");
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            let (kind, key) = token.split_at(token.chars().next().map_or(0, char::len_utf8));
+            let key = key
+                .parse::<i32>()
+                .map_err(|_| ImportError::Token(token.to_string()))?;
+            match kind {
+                "i" => Ok(Operation::Insert(key)),
+                "d" => Ok(Operation::Delete(key)),
+                "s" => Ok(Operation::Search(key)),
+                _ => Err(ImportError::Token(token.to_string())),
+            }
+        })
+        .collect()
+}
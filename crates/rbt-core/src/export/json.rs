@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use super::ImportError;
+use crate::augment::Standard;
+use crate::tree::{Color, Duplicates, Node, Pointer, RBTree};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum JsonColor {
+    Red,
+    Black,
+}
+
+#[derive(Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum JsonDuplicates {
+    #[default]
+    Reject,
+    Count,
+    Multiset,
+}
+
+fn is_one(count: &u32) -> bool {
+    *count == 1
+}
+
+fn one() -> u32 {
+    1
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonNode {
+    key: i32,
+    color: JsonColor,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    count: u32,
+    left: Option<Box<JsonNode>>,
+    right: Option<Box<JsonNode>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonTree {
+    #[serde(default, skip_serializing_if = "is_reject")]
+    duplicates: JsonDuplicates,
+    root: Option<Box<JsonNode>>,
+}
+
+fn is_reject(duplicates: &JsonDuplicates) -> bool {
+    *duplicates == JsonDuplicates::Reject
+}
+
+/// Serializes the tree as nested `{ key, color, left, right }` objects. Copy counts and a
+/// non-default duplicate policy are only written when present.
+pub fn to_json(tree: &RBTree) -> String {
+    let snapshot = JsonTree {
+        duplicates: match tree.duplicates() {
+            Duplicates::Reject => JsonDuplicates::Reject,
+            Duplicates::Count => JsonDuplicates::Count,
+            Duplicates::Multiset => JsonDuplicates::Multiset,
+        },
+        root: to_node(tree, tree.root),
+    };
+    serde_json::to_string(&snapshot).expect("snapshot serialization cannot fail")
+}
+
+fn to_node(tree: &RBTree, pointer: Pointer) -> Option<Box<JsonNode>> {
+    if pointer.is_null() {
+        return None;
+    }
+
+    let node = &tree[pointer];
+    Some(Box::new(JsonNode {
+        key: node.value,
+        color: match node.color {
+            Color::Red => JsonColor::Red,
+            Color::Black => JsonColor::Black,
+        },
+        count: node.count,
+        left: to_node(tree, node.left),
+        right: to_node(tree, node.right),
+    }))
+}
+
+/// Rebuilds a tree from [`to_json`] output, rejecting snapshots that break any invariant.
+pub fn from_json(input: &str) -> Result<RBTree, ImportError> {
+    let snapshot: JsonTree = serde_json::from_str(input)?;
+    let mut tree = RBTree::with_duplicates(match snapshot.duplicates {
+        JsonDuplicates::Reject => Duplicates::Reject,
+        JsonDuplicates::Count => Duplicates::Count,
+        JsonDuplicates::Multiset => Duplicates::Multiset,
+    });
+    if let Some(root) = snapshot.root {
+        tree.root = from_node(&mut tree, *root, Pointer::null());
+    }
+    tree.update_all();
+    tree.validate()?;
+    Ok(tree)
+}
+
+fn from_node(tree: &mut RBTree, node: JsonNode, parent: Pointer) -> Pointer {
+    let pointer = tree.alloc(Node::new::<Standard>(
+        node.key,
+        node.key,
+        parent,
+        match node.color {
+            JsonColor::Red => Color::Red,
+            JsonColor::Black => Color::Black,
+        },
+    ));
+    tree[pointer].count = node.count;
+
+    if let Some(left) = node.left {
+        tree[pointer].left = from_node(tree, *left, pointer);
+    }
+    if let Some(right) = node.right {
+        tree[pointer].right = from_node(tree, *right, pointer);
+    }
+    pointer
+}
@@ -0,0 +1,47 @@
+#[cfg(feature = "animation")]
+pub mod animation;
+pub mod dot;
+pub mod history;
+pub mod json;
+#[cfg(feature = "animation")]
+pub mod raster;
+pub mod script;
+pub mod svg;
+pub mod tikz;
+
+use std::fmt;
+
+use crate::invariants::Violation;
+
+#[derive(Debug)]
+pub enum ImportError {
+    Json(serde_json::Error),
+    Invalid(Violation),
+    Token(String),
+    Script { line: usize, text: String },
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Json(err) => write!(f, "malformed snapshot: {err}"),
+            ImportError::Invalid(violation) => write!(f, "not a valid red-black tree: {violation}"),
+            ImportError::Token(token) => write!(f, "unrecognised operation `{token}`"),
+            ImportError::Script { line, text } => write!(f, "line {line}: cannot parse `{text}`"),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+impl From<serde_json::Error> for ImportError {
+    fn from(err: serde_json::Error) -> Self {
+        ImportError::Json(err)
+    }
+}
+
+impl From<Violation> for ImportError {
+    fn from(violation: Violation) -> Self {
+        ImportError::Invalid(violation)
+    }
+}
@@ -0,0 +1,271 @@
+use tiny_skia::{FillRule, Paint, PathBuilder, Pixmap, Rect, Stroke, Transform};
+
+use crate::layout::{Bounds, TreeLayout, NODE_RADIUS};
+use crate::scene::{
+    fill, stroke, Scene, SceneOptions, ANNOTATION_FILL, ANNOTATION_SIZE, EDGE_STROKE, NIL_FILL,
+    NIL_SIZE,
+};
+use crate::tree::RBTree;
+
+const BACKGROUND: &str = "#ffffff";
+
+/// An opaque RGBA image, four bytes per pixel, row by row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+/// Draws the tree the way the SVG exporter does, with `area` of the canvas mapped onto the
+/// frame at `scale` pixels per unit. Returns `None` if the frame would be empty or too large.
+pub fn render_frame(
+    tree: &RBTree,
+    options: SceneOptions,
+    area: Bounds,
+    scale: f32,
+) -> Option<Frame> {
+    let width = (area.width() * scale).ceil() as u32;
+    let height = (area.height() * scale).ceil() as u32;
+    let mut pixmap = Pixmap::new(width, height)?;
+    pixmap.fill(color(BACKGROUND));
+
+    let layout = TreeLayout::new(tree);
+    let scene = Scene::with_options(tree, &layout, options);
+    let transform = Transform::from_translate(-area.min_x, -area.min_y).post_scale(scale, scale);
+
+    for edge in &scene.edges {
+        line(
+            &mut pixmap,
+            transform,
+            EDGE_STROKE,
+            0.8,
+            [edge.x1, edge.y1, edge.x2, edge.y2],
+        );
+        arrowhead(&mut pixmap, transform, [edge.x1, edge.y1, edge.x2, edge.y2]);
+    }
+
+    for nil in &scene.nils {
+        let edge = &nil.edge;
+        line(
+            &mut pixmap,
+            transform,
+            EDGE_STROKE,
+            0.5,
+            [edge.x1, edge.y1, edge.x2, edge.y2],
+        );
+        if let Some(rect) = Rect::from_xywh(
+            nil.x - NIL_SIZE / 2.0,
+            nil.y - NIL_SIZE / 2.0,
+            NIL_SIZE,
+            NIL_SIZE,
+        ) {
+            pixmap.fill_rect(rect, &paint(NIL_FILL), transform, None);
+        }
+    }
+
+    for node in &scene.nodes {
+        if let Some(circle) = PathBuilder::from_circle(node.x, node.y, NODE_RADIUS) {
+            pixmap.fill_path(
+                &circle,
+                &paint(fill(node.color)),
+                FillRule::Winding,
+                transform,
+                None,
+            );
+            let outline = Stroke {
+                width: 1.5,
+                ..Stroke::default()
+            };
+            pixmap.stroke_path(
+                &circle,
+                &paint(stroke(node.color)),
+                &outline,
+                transform,
+                None,
+            );
+        }
+
+        let label_width = text_width(&node.label, 6.0);
+        text(
+            &mut pixmap,
+            transform,
+            "#ffffff",
+            6.0,
+            (node.x - label_width / 2.0, node.y + 2.5),
+            &node.label,
+        );
+        if node.count > 1 {
+            text(
+                &mut pixmap,
+                transform,
+                ANNOTATION_FILL,
+                5.0,
+                (node.x + NODE_RADIUS - 2.0, node.y + NODE_RADIUS + 2.0),
+                &format!("×{}", node.count),
+            );
+        }
+        if let Some(annotation) = &node.annotation {
+            text(
+                &mut pixmap,
+                transform,
+                ANNOTATION_FILL,
+                ANNOTATION_SIZE,
+                (node.x + NODE_RADIUS + 1.0, node.y - NODE_RADIUS + 4.0),
+                annotation,
+            );
+        }
+    }
+
+    let rgba = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let pixel = pixel.demultiply();
+            [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+        })
+        .collect();
+    Some(Frame {
+        width,
+        height,
+        rgba,
+    })
+}
+
+fn color(hex: &str) -> tiny_skia::Color {
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+    tiny_skia::Color::from_rgba8(channel(1), channel(3), channel(5), 255)
+}
+
+fn paint(hex: &str) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color(color(hex));
+    paint.anti_alias = true;
+    paint
+}
+
+fn line(
+    pixmap: &mut Pixmap,
+    transform: Transform,
+    hex: &str,
+    width: f32,
+    [x1, y1, x2, y2]: [f32; 4],
+) {
+    let mut path = PathBuilder::new();
+    path.move_to(x1, y1);
+    path.line_to(x2, y2);
+    if let Some(path) = path.finish() {
+        let stroke = Stroke {
+            width,
+            ..Stroke::default()
+        };
+        pixmap.stroke_path(&path, &paint(hex), &stroke, transform, None);
+    }
+}
+
+// Matches the SVG marker: a small triangle whose tip touches the child's circle.
+fn arrowhead(pixmap: &mut Pixmap, transform: Transform, [x1, y1, x2, y2]: [f32; 4]) {
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length <= NODE_RADIUS {
+        return;
+    }
+    let (ux, uy) = (dx / length, dy / length);
+    let (tip_x, tip_y) = (x2 - ux * 9.8, y2 - uy * 9.8);
+    let (base_x, base_y) = (tip_x - ux * 2.9, tip_y - uy * 2.9);
+    let mut path = PathBuilder::new();
+    path.move_to(tip_x, tip_y);
+    path.line_to(base_x - uy * 1.45, base_y + ux * 1.45);
+    path.line_to(base_x + uy * 1.45, base_y - ux * 1.45);
+    path.close();
+    if let Some(path) = path.finish() {
+        pixmap.fill_path(
+            &path,
+            &paint(EDGE_STROKE),
+            FillRule::Winding,
+            transform,
+            None,
+        );
+    }
+}
+
+// Labels only ever hold keys, counts and annotations, so a tiny built-in bitmap font covers them
+// without depending on system fonts (there are none in the browser).
+const GLYPH_ROWS: usize = 7;
+const GLYPH_ADVANCE: f32 = 6.0;
+const CAP_HEIGHT: f32 = 0.72;
+
+fn glyph(c: char) -> [u8; GLYPH_ROWS] {
+    match c {
+        '0' => [0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e],
+        '1' => [0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e],
+        '2' => [0x0e, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1f],
+        '3' => [0x1e, 0x01, 0x01, 0x0e, 0x01, 0x01, 0x1e],
+        '4' => [0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02],
+        '5' => [0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e],
+        '6' => [0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e],
+        '7' => [0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e],
+        '9' => [0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c],
+        '-' => [0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00],
+        '=' => [0x00, 0x00, 0x1f, 0x00, 0x1f, 0x00, 0x00],
+        '×' => [0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x00],
+        'b' => [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1e],
+        'h' => [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x11],
+        'n' => [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11],
+        's' => [0x00, 0x00, 0x0f, 0x10, 0x0e, 0x01, 0x1e],
+        'u' => [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0d],
+        'm' => [0x00, 0x00, 0x1a, 0x15, 0x15, 0x11, 0x11],
+        'i' => [0x04, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x0e],
+        'a' => [0x00, 0x00, 0x0e, 0x01, 0x0f, 0x11, 0x0f],
+        'x' => [0x00, 0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11],
+        '[' => [0x0e, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0e],
+        ']' => [0x0e, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0e],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0c, 0x04, 0x08],
+        _ => [0; GLYPH_ROWS],
+    }
+}
+
+fn cell(font_size: f32) -> f32 {
+    font_size * CAP_HEIGHT / GLYPH_ROWS as f32
+}
+
+fn text_width(text: &str, font_size: f32) -> f32 {
+    let glyphs = text.chars().count() as f32;
+    (glyphs * GLYPH_ADVANCE - 1.0).max(0.0) * cell(font_size)
+}
+
+// Draws `text` with its baseline starting at `origin`.
+fn text(
+    pixmap: &mut Pixmap,
+    transform: Transform,
+    hex: &str,
+    font_size: f32,
+    (x, baseline): (f32, f32),
+    text: &str,
+) {
+    let cell = cell(font_size);
+    let top = baseline - GLYPH_ROWS as f32 * cell;
+    let paint = paint(hex);
+    let mut path = PathBuilder::new();
+    for (i, c) in text.chars().enumerate() {
+        let left = x + i as f32 * GLYPH_ADVANCE * cell;
+        for (row, bits) in glyph(c).iter().enumerate() {
+            for column in 0..5 {
+                if bits & (0x10 >> column) != 0 {
+                    if let Some(rect) = Rect::from_xywh(
+                        left + column as f32 * cell,
+                        top + row as f32 * cell,
+                        cell,
+                        cell,
+                    ) {
+                        path.push_rect(rect);
+                    }
+                }
+            }
+        }
+    }
+    if let Some(path) = path.finish() {
+        pixmap.fill_path(&path, &paint, FillRule::Winding, transform, None);
+    }
+}
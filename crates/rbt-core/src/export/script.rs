@@ -0,0 +1,42 @@
+use super::ImportError;
+use crate::trace::Operation;
+
+/// Parses an operation script: one command per line, e.g. `insert 5 3 8`, `delete 3` or
+/// `search 8` (`i`, `d` and `s` also work). Blank lines and `#` comments are ignored.
+pub fn parse(input: &str) -> Result<Vec<Operation>, ImportError> {
+    let mut operations = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            continue;
+        };
+
+        let operation: fn(i32) -> Operation = match command.to_ascii_lowercase().as_str() {
+            "insert" | "i" => Operation::Insert,
+            "delete" | "d" => Operation::Delete,
+            "search" | "s" => Operation::Search,
+            _ => return Err(script_error(index, command)),
+        };
+
+        let mut keys = 0;
+        for word in words {
+            let key = word.parse().map_err(|_| script_error(index, word))?;
+            operations.push(operation(key));
+            keys += 1;
+        }
+        if keys == 0 {
+            return Err(script_error(index, line.trim()));
+        }
+    }
+
+    Ok(operations)
+}
+
+fn script_error(index: usize, text: &str) -> ImportError {
+    ImportError::Script {
+        line: index + 1,
+        text: text.to_string(),
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt::Write;
+
+use crate::layout::{TreeLayout, NODE_RADIUS};
+use crate::scene::{
+    fill, frame_bounds, stroke, Scene, SceneOptions, ANNOTATION_FILL, ANNOTATION_SIZE, EDGE_STROKE,
+    FRAME_PADDING, NIL_FILL, NIL_SIZE,
+};
+use crate::tree::RBTree;
+
+/// Renders the tree as a standalone SVG document sized to fit everything drawn.
+pub fn to_svg(tree: &RBTree) -> String {
+    to_svg_with(tree, SceneOptions::default())
+}
+
+pub fn to_svg_with(tree: &RBTree, options: SceneOptions) -> String {
+    let layout = TreeLayout::new(tree);
+    let scene = Scene::with_options(tree, &layout, options);
+    let (x, y, width, height) = match frame_bounds(&scene) {
+        Some(bounds) => (bounds.min_x, bounds.min_y, bounds.width(), bounds.height()),
+        None => (0.0, 0.0, 2.0 * FRAME_PADDING, 2.0 * FRAME_PADDING),
+    };
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{x} {y} {width} {height}" width="{}" height="{}">"#,
+        width * 4.0,
+        height * 4.0
+    );
+    let _ = writeln!(
+        svg,
+        r#"<defs><marker id="arrowhead" viewBox="0 0 10 10" refX="13.2" refY="1.5" markerWidth="12" markerHeight="16" orient="auto"><path d="M0,0 L0,3 L3,1.5 z" fill="{EDGE_STROKE}"/></marker></defs>"#
+    );
+
+    for edge in &scene.edges {
+        let _ = writeln!(
+            svg,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{EDGE_STROKE}" stroke-width="0.8" marker-end="url(#arrowhead)"/>"#,
+            edge.x1, edge.y1, edge.x2, edge.y2
+        );
+    }
+
+    for nil in &scene.nils {
+        let _ = writeln!(
+            svg,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{EDGE_STROKE}" stroke-width="0.5"/>"#,
+            nil.edge.x1, nil.edge.y1, nil.edge.x2, nil.edge.y2
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{}" y="{}" width="{NIL_SIZE}" height="{NIL_SIZE}" fill="{NIL_FILL}"/>"#,
+            nil.x - NIL_SIZE / 2.0,
+            nil.y - NIL_SIZE / 2.0
+        );
+    }
+
+    for node in &scene.nodes {
+        let _ = writeln!(
+            svg,
+            r#"<circle cx="{}" cy="{}" r="{NODE_RADIUS}" stroke="{}" stroke-width="1.5" fill="{}"/>"#,
+            node.x,
+            node.y,
+            stroke(node.color),
+            fill(node.color)
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="middle" fill="white" font-size="6" font-weight="bold" font-family="sans-serif">{}</text>"#,
+            node.x,
+            node.y + 2.5,
+            node.label
+        );
+        if node.count > 1 {
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" fill="{ANNOTATION_FILL}" font-size="5" font-weight="bold" font-family="sans-serif">×{}</text>"#,
+                node.x + NODE_RADIUS - 2.0,
+                node.y + NODE_RADIUS + 2.0,
+                node.count
+            );
+        }
+        if let Some(annotation) = &node.annotation {
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" fill="{ANNOTATION_FILL}" font-size="{ANNOTATION_SIZE}" font-family="sans-serif">{annotation}</text>"#,
+                node.x + NODE_RADIUS + 1.0,
+                node.y - NODE_RADIUS + 4.0
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
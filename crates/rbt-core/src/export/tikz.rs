@@ -0,0 +1,140 @@
+use std::fmt::Write;
+
+use crate::scene::{Scene, SceneOptions, BLACK_FILL, BLACK_STROKE, NIL_FILL, RED_FILL, RED_STROKE};
+use crate::tree::{Color, Pointer, RBTree};
+
+/// Which LaTeX package the exported source draws the tree with.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Package {
+    #[default]
+    Forest,
+    Qtree,
+}
+
+/// Renders the tree as a LaTeX snippet for `package` (which the document must load, along
+/// with TikZ). Colours match the canvas; NIL leaves and annotations follow `options`.
+pub fn to_tikz(tree: &RBTree, package: Package, options: SceneOptions) -> String {
+    let mut tex = String::new();
+    let _ = writeln!(
+        tex,
+        "% Requires \\usepackage{{{}}}",
+        match package {
+            Package::Forest => "forest",
+            Package::Qtree => "tikz-qtree",
+        }
+    );
+    for (name, hex) in [
+        ("rbred", RED_FILL),
+        ("rbredline", RED_STROKE),
+        ("rbblack", BLACK_FILL),
+        ("rbblackline", BLACK_STROKE),
+        ("rbnil", NIL_FILL),
+    ] {
+        let _ = writeln!(tex, "\\definecolor{{{name}}}{{HTML}}{{{}}}", &hex[1..]);
+    }
+    let _ = writeln!(
+        tex,
+        "\\tikzset{{\n  rb/.style={{circle, draw, thick, minimum size=2.2em, inner sep=1pt, text=white, font=\\sffamily\\bfseries}},\n  rb red/.style={{rb, fill=rbred, draw=rbredline}},\n  rb black/.style={{rb, fill=rbblack, draw=rbblackline}},\n  rb nil/.style={{rectangle, fill=rbnil, minimum size=0.5em, inner sep=0pt}},\n}}"
+    );
+
+    match package {
+        Package::Forest => {
+            tex.push_str("\\begin{forest}\n");
+            if !tree.root.is_null() {
+                forest(&mut tex, tree, options, tree.root, 1);
+            }
+            tex.push_str("\\end{forest}\n");
+        }
+        Package::Qtree => {
+            tex.push_str("\\begin{tikzpicture}\n");
+            if !tree.root.is_null() {
+                tex.push_str("\\Tree ");
+                qtree(&mut tex, tree, options, tree.root, true);
+                tex.truncate(tex.trim_end().len());
+                tex.push('\n');
+            }
+            tex.push_str("\\end{tikzpicture}\n");
+        }
+    }
+    tex
+}
+
+fn style(color: Color) -> &'static str {
+    match color {
+        Color::Red => "rb red",
+        Color::Black => "rb black",
+    }
+}
+
+fn label(tree: &RBTree, pointer: Pointer) -> String {
+    let node = &tree[pointer];
+    if node.count > 1 {
+        format!("{}$\\times${}", node.value, node.count)
+    } else {
+        node.value.to_string()
+    }
+}
+
+fn forest(tex: &mut String, tree: &RBTree, options: SceneOptions, pointer: Pointer, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let node = &tree[pointer];
+    let annotation = Scene::annotation(tree, options, pointer)
+        .map(|annotation| format!(", label=right:{{\\scriptsize {annotation}}}"))
+        .unwrap_or_default();
+    let _ = write!(
+        tex,
+        "{indent}[{{{}}}, {}{annotation}",
+        label(tree, pointer),
+        style(node.color)
+    );
+
+    let children = [node.left, node.right];
+    if children.iter().all(|child| child.is_null()) && !options.nil_leaves {
+        tex.push_str("]\n");
+        return;
+    }
+    tex.push('\n');
+    for child in children {
+        if !child.is_null() {
+            forest(tex, tree, options, child, depth + 1);
+        } else if options.nil_leaves {
+            let _ = writeln!(tex, "{indent}  [, rb nil]");
+        } else {
+            // Keeps an only child on its own side instead of centred below the parent.
+            let _ = writeln!(tex, "{indent}  [, phantom]");
+        }
+    }
+    let _ = writeln!(tex, "{indent}]");
+}
+
+fn qtree(tex: &mut String, tree: &RBTree, options: SceneOptions, pointer: Pointer, root: bool) {
+    let node = &tree[pointer];
+    let annotation = Scene::annotation(tree, options, pointer)
+        .map(|annotation| format!(", label=right:{{\\scriptsize {annotation}}}"))
+        .unwrap_or_default();
+    let content = format!(
+        "\\node[{}{annotation}]{{{}}};",
+        style(node.color),
+        label(tree, pointer)
+    );
+
+    let children = [node.left, node.right];
+    if children.iter().all(|child| child.is_null()) && !options.nil_leaves && !root {
+        tex.push_str(&content);
+        tex.push(' ');
+        return;
+    }
+    let _ = write!(tex, "[.{content} ");
+    if children.iter().any(|child| !child.is_null()) || options.nil_leaves {
+        for child in children {
+            if !child.is_null() {
+                qtree(tex, tree, options, child, false);
+            } else if options.nil_leaves {
+                tex.push_str("\\node[rb nil]{}; ");
+            } else {
+                tex.push_str("\\edge[draw=none]; \\node[draw=none]{}; ");
+            }
+        }
+    }
+    tex.push_str("] ");
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+
+use crate::augment::Max;
+use crate::tree::{Duplicates, Pointer, RBTree};
+
+/// A closed interval `[lo, hi]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interval {
+    pub lo: i32,
+    pub hi: i32,
+}
+
+impl Interval {
+    /// The interval between `a` and `b`, whichever order they come in.
+    pub fn new(a: i32, b: i32) -> Interval {
+        Interval {
+            lo: a.min(b),
+            hi: a.max(b),
+        }
+    }
+
+    pub fn overlaps(&self, other: Interval) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+/// The interval tree of CLRS 14.3: a red-black tree keyed on each interval's low endpoint, whose
+/// nodes also keep the largest high endpoint in their subtree ([`Max`]). Rotations keep that
+/// augmentation up to date, which is what lets a search skip subtrees that cannot overlap.
+#[derive(Clone, Debug)]
+pub struct IntervalTree {
+    tree: RBTree,
+}
+
+impl Default for IntervalTree {
+    fn default() -> Self {
+        IntervalTree::new()
+    }
+}
+
+impl IntervalTree {
+    pub fn new() -> Self {
+        // Several intervals may start at the same point.
+        IntervalTree {
+            tree: RBTree::with_duplicates(Duplicates::Multiset),
+        }
+    }
+
+    /// The underlying red-black tree, for drawing and validation.
+    pub fn tree(&self) -> &RBTree {
+        &self.tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// The interval stored at `node`.
+    pub fn interval(&self, node: Pointer) -> Interval {
+        Interval {
+            lo: self.tree[node].value,
+            hi: self.tree[node].high,
+        }
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        self.tree.insert_with_high(interval.lo, interval.hi);
+    }
+
+    /// Removes one copy of `interval`, returning whether it was present.
+    pub fn remove(&mut self, interval: Interval) -> bool {
+        let node = self.find(self.tree.root, interval);
+        if node.is_null() {
+            return false;
+        }
+        self.tree.remove_node(node);
+        true
+    }
+
+    // Equal low endpoints can sit on either side of each other after rotations, so both
+    // subtrees are searched on a tie.
+    fn find(&self, node: Pointer, interval: Interval) -> Pointer {
+        if node.is_null() {
+            return node;
+        }
+        let current = &self.tree[node];
+        if interval.lo < current.value {
+            self.find(current.left, interval)
+        } else if interval.lo > current.value {
+            self.find(current.right, interval)
+        } else if interval.hi == current.high {
+            node
+        } else {
+            let left = self.find(current.left, interval);
+            if left.is_null() {
+                self.find(current.right, interval)
+            } else {
+                left
+            }
+        }
+    }
+
+    /// CLRS INTERVAL-SEARCH: some node whose interval overlaps `query`, or null if none does.
+    /// Also returns the nodes visited on the way down.
+    pub fn search(&self, query: Interval) -> (Pointer, Vec<Pointer>) {
+        let mut path = Vec::new();
+        let mut node = self.tree.root;
+        while !node.is_null() && !self.interval(node).overlaps(query) {
+            path.push(node);
+            let left = self.tree[node].left;
+            node = if !left.is_null() && self.tree.aggregate::<Max>(left) >= query.lo {
+                left
+            } else {
+                self.tree[node].right
+            };
+        }
+        if !node.is_null() {
+            path.push(node);
+        }
+        (node, path)
+    }
+
+    /// Every node whose interval overlaps `query`, in order of low endpoint. Subtrees whose
+    /// `max` falls short of `query.lo`, or that start after `query.hi`, are never entered.
+    pub fn overlapping(&self, query: Interval) -> Vec<Pointer> {
+        fn collect(tree: &IntervalTree, node: Pointer, query: Interval, found: &mut Vec<Pointer>) {
+            if node.is_null() || tree.tree.aggregate::<Max>(node) < query.lo {
+                return;
+            }
+            collect(tree, tree.tree[node].left, query, found);
+            if tree.interval(node).overlaps(query) {
+                found.push(node);
+            }
+            if tree.tree[node].value <= query.hi {
+                collect(tree, tree.tree[node].right, query, found);
+            }
+        }
+
+        let mut found = Vec::new();
+        collect(self, self.tree.root, query, &mut found);
+        found
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+
+use crate::augment::Augmentation;
+use crate::tree::{Color, Duplicates, Pointer, RBTree};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Violation {
+    RedRoot,
+    RedRed { node: Pointer },
+    BlackHeight { node: Pointer },
+    Order { node: Pointer },
+    ParentLink { node: Pointer },
+    Size { node: Pointer },
+    Count { node: Pointer },
+    Aggregate { node: Pointer },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::RedRoot => write!(f, "the root is red"),
+            Violation::RedRed { .. } => write!(f, "a red node has a red child"),
+            Violation::BlackHeight { .. } => {
+                write!(f, "paths below a node have different black heights")
+            }
+            Violation::Order { .. } => write!(f, "keys are out of search-tree order"),
+            Violation::ParentLink { .. } => write!(f, "a child does not point back to its parent"),
+            Violation::Size { .. } => write!(f, "a subtree size is stale"),
+            Violation::Aggregate { .. } => write!(f, "a subtree aggregate is stale"),
+            Violation::Count { .. } => {
+                write!(
+                    f,
+                    "a node's copy count is not allowed by the duplicate policy"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Violation {}
+
+impl Violation {
+    /// The node the violation was found at, if it is tied to one.
+    pub fn node(&self) -> Option<Pointer> {
+        match *self {
+            Violation::RedRoot => None,
+            Violation::RedRed { node }
+            | Violation::BlackHeight { node }
+            | Violation::Order { node }
+            | Violation::ParentLink { node }
+            | Violation::Size { node }
+            | Violation::Count { node }
+            | Violation::Aggregate { node } => Some(node),
+        }
+    }
+}
+
+impl<A: Augmentation> RBTree<A> {
+    /// Every red-black, ordering and bookkeeping violation in the tree.
+    pub fn violations(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if !self.root.is_null() {
+            if self[self.root].color == Color::Red {
+                violations.push(Violation::RedRoot);
+            }
+            if !self[self.root].parent.is_null() {
+                violations.push(Violation::ParentLink { node: self.root });
+            }
+            self.check(self.root, None, None, &mut violations);
+        }
+        violations
+    }
+
+    pub fn validate(&self) -> Result<(), Violation> {
+        match self.violations().first() {
+            Some(violation) => Err(*violation),
+            None => Ok(()),
+        }
+    }
+
+    // Returns the black height of the subtree rooted at `node`.
+    fn check(
+        &self,
+        node: Pointer,
+        lo: Option<i32>,
+        hi: Option<i32>,
+        violations: &mut Vec<Violation>,
+    ) -> u32 {
+        if node.is_null() {
+            return 1;
+        }
+
+        let current = &self[node];
+        // Rotations can move equal keys to either side of each other in a multiset.
+        let out_of_order = if self.duplicates() == Duplicates::Multiset {
+            lo.is_some_and(|lo| current.value < lo) || hi.is_some_and(|hi| current.value > hi)
+        } else {
+            lo.is_some_and(|lo| current.value <= lo) || hi.is_some_and(|hi| current.value >= hi)
+        };
+        if out_of_order {
+            violations.push(Violation::Order { node });
+        }
+        if current.count == 0 || (current.count > 1 && self.duplicates() != Duplicates::Count) {
+            violations.push(Violation::Count { node });
+        }
+
+        for child in [current.left, current.right] {
+            if child.is_null() {
+                continue;
+            }
+            if self[child].parent != node {
+                violations.push(Violation::ParentLink { node: child });
+            }
+            if current.color == Color::Red && self[child].color == Color::Red {
+                violations.push(Violation::RedRed { node: child });
+            }
+        }
+
+        let left_height = self.check(current.left, lo, Some(current.value), violations);
+        let right_height = self.check(current.right, Some(current.value), hi, violations);
+
+        if left_height != right_height {
+            violations.push(Violation::BlackHeight { node });
+        }
+        // Children are checked first, so comparing against their stored values is enough.
+        if current.size != self.recompute_size(node) {
+            violations.push(Violation::Size { node });
+        }
+        if current.aggregate != self.recompute(node) {
+            violations.push(Violation::Aggregate { node });
+        }
+
+        let own = if current.color == Color::Black { 1 } else { 0 };
+        left_height.max(right_height) + own
+    }
+}
@@ -0,0 +1,163 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::augment::{Augmentation, Standard};
+use crate::tree::{Node, Pointer, RBTree};
+
+/// In-order iterator over the nodes of an [`RBTree`], from either end. A node holding several
+/// copies of its key under [`crate::Duplicates::Count`] is visited once.
+#[derive(Clone, Debug)]
+pub struct Iter<'a, A: Augmentation = Standard> {
+    tree: &'a RBTree<A>,
+    // Next node from each end; both null once they have met.
+    front: Pointer,
+    back: Pointer,
+}
+
+impl<'a, A: Augmentation> Iterator for Iter<'a, A> {
+    type Item = (Pointer, &'a Node<A::Value>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front.is_null() {
+            return None;
+        }
+        let node = self.front;
+        if node == self.back {
+            self.front = Pointer::null();
+            self.back = Pointer::null();
+        } else {
+            self.front = self.tree.successor(node);
+        }
+        Some((node, &self.tree[node]))
+    }
+}
+
+impl<A: Augmentation> DoubleEndedIterator for Iter<'_, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.back.is_null() {
+            return None;
+        }
+        let node = self.back;
+        if node == self.front {
+            self.front = Pointer::null();
+            self.back = Pointer::null();
+        } else {
+            self.back = self.tree.predecessor(node);
+        }
+        Some((node, &self.tree[node]))
+    }
+}
+
+impl<A: Augmentation> RBTree<A> {
+    pub fn iter(&self) -> Iter<'_, A> {
+        Iter {
+            tree: self,
+            front: self.first(),
+            back: self.last(),
+        }
+    }
+
+    /// Every key in order, repeating counted keys once per copy.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = i32> + '_ {
+        self.iter()
+            .flat_map(|(_, node)| std::iter::repeat_n(node.value, node.count as usize))
+    }
+
+    /// In-order iterator over the nodes whose keys fall in `range`, e.g. `tree.range(10..20)`.
+    pub fn range(&self, range: impl RangeBounds<i32>) -> Iter<'_, A> {
+        let front = match range.start_bound() {
+            Bound::Included(&lo) => self.ceiling(lo),
+            Bound::Excluded(&lo) => self.higher(lo),
+            Bound::Unbounded => self.first(),
+        };
+        let back = match range.end_bound() {
+            Bound::Included(&hi) => self.floor(hi),
+            Bound::Excluded(&hi) => self.lower(hi),
+            Bound::Unbounded => self.last(),
+        };
+        if front.is_null() || back.is_null() || self[front].value > self[back].value {
+            return Iter {
+                tree: self,
+                front: Pointer::null(),
+                back: Pointer::null(),
+            };
+        }
+        Iter {
+            tree: self,
+            front,
+            back,
+        }
+    }
+
+    /// The node with the smallest key, or null if the tree is empty.
+    pub fn first(&self) -> Pointer {
+        if self.root.is_null() {
+            self.root
+        } else {
+            self.minimum(self.root)
+        }
+    }
+
+    /// The node with the largest key, or null if the tree is empty.
+    pub fn last(&self) -> Pointer {
+        if self.root.is_null() {
+            self.root
+        } else {
+            self.maximum(self.root)
+        }
+    }
+
+    /// The node with the largest key at most `val`, or null if there is none.
+    pub fn floor(&self, val: i32) -> Pointer {
+        self.last_where(|key| key <= val)
+    }
+
+    /// The node with the smallest key at least `val`, or null if there is none.
+    pub fn ceiling(&self, val: i32) -> Pointer {
+        self.first_where(|key| key >= val)
+    }
+
+    /// The node with the largest key below `val`: the predecessor of `val` whether or not it is
+    /// in the tree.
+    pub fn lower(&self, val: i32) -> Pointer {
+        self.last_where(|key| key < val)
+    }
+
+    /// The node with the smallest key above `val`: the successor of `val` whether or not it is
+    /// in the tree.
+    pub fn higher(&self, val: i32) -> Pointer {
+        self.first_where(|key| key > val)
+    }
+
+    // The first node in order whose key passes `test`, which must be false up to some key and
+    // true after it. Equal keys may sit on either side of each other, so the descent keeps
+    // going after a match to find the leftmost one.
+    fn first_where(&self, test: impl Fn(i32) -> bool) -> Pointer {
+        let mut found = Pointer::null();
+        let mut node = self.root;
+        while !node.is_null() {
+            if test(self[node].value) {
+                found = node;
+                node = self[node].left;
+            } else {
+                node = self[node].right;
+            }
+        }
+        found
+    }
+
+    // The last node in order whose key passes `test`, which must be true up to some key and
+    // false after it.
+    fn last_where(&self, test: impl Fn(i32) -> bool) -> Pointer {
+        let mut found = Pointer::null();
+        let mut node = self.root;
+        while !node.is_null() {
+            if test(self[node].value) {
+                found = node;
+                node = self[node].right;
+            } else {
+                node = self[node].left;
+            }
+        }
+        found
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use crate::tree::{Pointer, RBTree};
+
+pub const ROOT_X: f32 = 100.0;
+pub const ROOT_Y: f32 = 20.0;
+pub const LEVEL_GAP: f32 = 30.0;
+pub const SIZE_GAP: f32 = 4.0;
+pub const NODE_RADIUS: f32 = 10.0;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Position {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// An axis-aligned rectangle on the canvas.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bounds {
+    pub min_x: f32,
+    pub min_y: f32,
+    pub max_x: f32,
+    pub max_y: f32,
+}
+
+impl Bounds {
+    /// The square of half-width `half` centred on `(x, y)`.
+    pub fn around(x: f32, y: f32, half: f32) -> Bounds {
+        Bounds {
+            min_x: x - half,
+            min_y: y - half,
+            max_x: x + half,
+            max_y: y + half,
+        }
+    }
+
+    /// Smallest rectangle containing both.
+    pub fn union(self, other: Bounds) -> Bounds {
+        Bounds {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    /// Grown by `padding` on every side.
+    pub fn padded(self, padding: f32) -> Bounds {
+        Bounds {
+            min_x: self.min_x - padding,
+            min_y: self.min_y - padding,
+            max_x: self.max_x + padding,
+            max_y: self.max_y + padding,
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.max_x - self.min_x
+    }
+
+    pub fn height(&self) -> f32 {
+        self.max_y - self.min_y
+    }
+}
+
+/// Canvas coordinates of every node, spreading children apart by their parent's subtree size.
+#[derive(Clone, Debug, Default)]
+pub struct TreeLayout {
+    positions: HashMap<Pointer, Position>,
+}
+
+impl TreeLayout {
+    pub fn new(tree: &RBTree) -> Self {
+        let mut layout = TreeLayout::default();
+        if !tree.root.is_null() {
+            layout.place(tree, tree.root, ROOT_X, ROOT_Y);
+        }
+        layout
+    }
+
+    fn place(&mut self, tree: &RBTree, pointer: Pointer, x: f32, y: f32) {
+        self.positions.insert(pointer, Position { x, y });
+
+        let node = &tree[pointer];
+        let h_gap = SIZE_GAP * (node.size as f32);
+        if !node.left.is_null() {
+            self.place(tree, node.left, x - h_gap, y + LEVEL_GAP);
+        }
+        if !node.right.is_null() {
+            self.place(tree, node.right, x + h_gap, y + LEVEL_GAP);
+        }
+    }
+
+    pub fn position(&self, pointer: Pointer) -> Option<Position> {
+        self.positions.get(&pointer).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pointer, Position)> + '_ {
+        self.positions
+            .iter()
+            .map(|(pointer, position)| (*pointer, *position))
+    }
+
+    /// Smallest rectangle containing every node circle.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.positions
+            .values()
+            .map(|position| Bounds::around(position.x, position.y, NODE_RADIUS))
+            .reduce(Bounds::union)
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+}
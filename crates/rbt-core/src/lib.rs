@@ -0,0 +1,22 @@
+pub mod augment;
+pub mod edit;
+pub mod export;
+pub mod interval;
+pub mod invariants;
+pub mod iter;
+pub mod layout;
+pub mod narration;
+pub mod pseudocode;
+pub mod quiz;
+pub mod scene;
+pub mod stats;
+pub mod trace;
+pub mod tree;
+pub mod viewport;
+
+pub use layout::{Bounds, Position, TreeLayout};
+pub use pseudocode::{Highlight, Procedure};
+pub use scene::{Scene, SceneOptions};
+pub use trace::{Case, Event, Operation, Removal, Step, Trace};
+pub use tree::{Color, Duplicates, Node, Pointer, RBTree};
+pub use viewport::ViewBox;
@@ -0,0 +1,188 @@
+use crate::trace::{Case, Event, Operation, Removal, Trace};
+use crate::tree::{Color, Duplicates, Pointer, RBTree};
+
+/// Describes every step of `trace` in one sentence, in the order they were recorded.
+pub fn narrate(trace: &Trace) -> Vec<String> {
+    trace
+        .steps
+        .iter()
+        .map(|step| describe(trace.operation, &step.tree, step.event))
+        .collect()
+}
+
+fn describe(operation: Operation, tree: &RBTree, event: Event) -> String {
+    match event {
+        Event::Visited { node } => visited(operation, tree, node),
+        Event::Inserted { node } => {
+            let parent = tree[node].parent;
+            if parent.is_null() {
+                format!("Insert {} as the black root", tree[node].value)
+            } else {
+                let side = if tree[parent].left == node {
+                    "left"
+                } else {
+                    "right"
+                };
+                format!(
+                    "Insert {} as the red {side} child of {}",
+                    tree[node].value, tree[parent].value
+                )
+            }
+        }
+        Event::Removed {
+            key,
+            removal,
+            replacement,
+        } => match removal {
+            Removal::NoLeftChild | Removal::NoRightChild if replacement.is_null() => {
+                format!("Remove {key}, which has no children")
+            }
+            Removal::NoLeftChild => format!(
+                "Remove {key}; its right child {} takes its place",
+                tree[replacement].value
+            ),
+            Removal::NoRightChild => format!(
+                "Remove {key}; its left child {} takes its place",
+                tree[replacement].value
+            ),
+            Removal::Successor => format!(
+                "Remove {key}; its successor {} takes its place and color",
+                tree[replacement].value
+            ),
+        },
+        Event::Case { case, node, parent } => case_sentence(tree, case, node, parent),
+        Event::CountChanged { node, count } => {
+            let direction = if matches!(operation, Operation::Insert(_)) {
+                "Raise"
+            } else {
+                "Lower"
+            };
+            format!("{direction} the count of {} to {count}", tree[node].value)
+        }
+        Event::Recolored { node, color } => {
+            format!("Color {} {}", tree[node].value, color_name(color))
+        }
+        Event::RotatedLeft { node } => format!(
+            "Rotate left at {}: {} moves up",
+            tree[node].value, tree[tree[node].parent].value
+        ),
+        Event::RotatedRight { node } => format!(
+            "Rotate right at {}: {} moves up",
+            tree[node].value, tree[tree[node].parent].value
+        ),
+    }
+}
+
+fn visited(operation: Operation, tree: &RBTree, node: Pointer) -> String {
+    let key = match operation {
+        Operation::Insert(key) | Operation::Delete(key) | Operation::Search(key) => key,
+    };
+    let value = tree[node].value;
+    let multiset = tree.duplicates() == Duplicates::Multiset;
+    if key == value && !(multiset && matches!(operation, Operation::Insert(_))) {
+        return match (operation, tree.duplicates()) {
+            (Operation::Insert(_), Duplicates::Reject) => {
+                format!("{key} is already in the tree, so it is not inserted again")
+            }
+            (Operation::Insert(_), _) => format!("{key} is already in the tree"),
+            _ => format!("Found {key}"),
+        };
+    }
+
+    // Equal keys go right when a multiset inserts a copy.
+    let (side, child) = if key < value {
+        ("left", tree[node].left)
+    } else {
+        ("right", tree[node].right)
+    };
+    if child.is_null() && !matches!(operation, Operation::Insert(_)) {
+        format!("Compare {key} with {value}: the {side} child is NIL, so {key} is not in the tree")
+    } else {
+        format!("Compare {key} with {value}: go {side}")
+    }
+}
+
+fn case_sentence(tree: &RBTree, case: Case, node: Pointer, parent: Pointer) -> String {
+    match case {
+        Case::InsertRoot => format!("{} is the root → color it black", tree[node].value),
+        Case::InsertRedUncle | Case::InsertTriangle | Case::InsertLine => {
+            let (z, p) = (tree[node].value, tree[parent].value);
+            let grandparent = tree[parent].parent;
+            let g = tree[grandparent].value;
+            let mirrored = tree[grandparent].right == parent;
+            let uncle = if mirrored {
+                tree[grandparent].left
+            } else {
+                tree[grandparent].right
+            };
+            let uncle = key(tree, uncle);
+            let (inner, outer) = if mirrored {
+                ("right", "left")
+            } else {
+                ("left", "right")
+            };
+            match case {
+                Case::InsertRedUncle => format!(
+                    "Uncle {uncle} is red → recolor parent {p} and uncle {uncle} black, grandparent {g} red (case 1)"
+                ),
+                Case::InsertTriangle => format!(
+                    "Uncle {uncle} is black and {z} is an inner child → rotate parent {p} {inner} (case 2)"
+                ),
+                _ => format!(
+                    "Uncle {uncle} is black and {z} is an outer child → color parent {p} black, grandparent {g} red and rotate {g} {outer} (case 3)"
+                ),
+            }
+        }
+        _ => {
+            let x = key(tree, node);
+            let p = tree[parent].value;
+            let mirrored = tree[parent].left != node;
+            let sibling = if mirrored {
+                tree[parent].left
+            } else {
+                tree[parent].right
+            };
+            let w = tree[sibling].value;
+            let (near, far) = if mirrored {
+                (tree[sibling].right, tree[sibling].left)
+            } else {
+                (tree[sibling].left, tree[sibling].right)
+            };
+            let (near, far) = (key(tree, near), key(tree, far));
+            let (toward, away) = if mirrored {
+                ("right", "left")
+            } else {
+                ("left", "right")
+            };
+            match case {
+                Case::DeleteRedSibling => format!(
+                    "{x} is doubly black and sibling {w} is red → color {w} black, parent {p} red and rotate {p} {toward} (case 1)"
+                ),
+                Case::DeleteBlackNephews => format!(
+                    "Sibling {w} has two black children → color {w} red and push the extra black from {x} up to {p} (case 2)"
+                ),
+                Case::DeleteNearNephewRed => format!(
+                    "Sibling {w}'s far child is black but its near child {near} is red → swap their colors and rotate {w} {away} (case 3)"
+                ),
+                _ => format!(
+                    "Sibling {w}'s far child {far} is red → give {w} the color of {p}, color {p} and {far} black and rotate {p} {toward} (case 4)"
+                ),
+            }
+        }
+    }
+}
+
+fn key(tree: &RBTree, node: Pointer) -> String {
+    if node.is_null() {
+        "NIL".to_string()
+    } else {
+        tree[node].value.to_string()
+    }
+}
+
+fn color_name(color: Color) -> &'static str {
+    match color {
+        Color::Red => "red",
+        Color::Black => "black",
+    }
+}
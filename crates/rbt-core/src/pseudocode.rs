@@ -0,0 +1,428 @@
+use crate::trace::{Case, Event, Operation, Removal, Trace};
+use crate::tree::{Pointer, RBTree};
+
+const TREE_SEARCH: &[&str] = &[
+    "while x ≠ NIL and k ≠ x.key",
+    "    if k < x.key",
+    "        x = x.left",
+    "    else x = x.right",
+    "return x",
+];
+
+const RB_INSERT: &[&str] = &[
+    "y = T.nil",
+    "x = T.root",
+    "while x ≠ T.nil",
+    "    y = x",
+    "    if z.key < x.key",
+    "        x = x.left",
+    "    else x = x.right",
+    "z.p = y",
+    "if y == T.nil",
+    "    T.root = z",
+    "elseif z.key < y.key",
+    "    y.left = z",
+    "else y.right = z",
+    "z.left = T.nil",
+    "z.right = T.nil",
+    "z.color = RED",
+    "RB-INSERT-FIXUP(T, z)",
+];
+
+const RB_INSERT_FIXUP: &[&str] = &[
+    "while z.p.color == RED",
+    "    if z.p == z.p.p.left",
+    "        y = z.p.p.right",
+    "        if y.color == RED",
+    "            z.p.color = BLACK",
+    "            y.color = BLACK",
+    "            z.p.p.color = RED",
+    "            z = z.p.p",
+    "        else if z == z.p.right",
+    "                z = z.p",
+    "                LEFT-ROTATE(T, z)",
+    "            z.p.color = BLACK",
+    "            z.p.p.color = RED",
+    "            RIGHT-ROTATE(T, z.p.p)",
+    "    else (same with \"right\" and \"left\" exchanged)",
+    "T.root.color = BLACK",
+];
+
+const LEFT_ROTATE: &[&str] = &[
+    "y = x.right",
+    "x.right = y.left",
+    "if y.left ≠ T.nil",
+    "    y.left.p = x",
+    "y.p = x.p",
+    "if x.p == T.nil",
+    "    T.root = y",
+    "elseif x == x.p.left",
+    "    x.p.left = y",
+    "else x.p.right = y",
+    "y.left = x",
+    "x.p = y",
+];
+
+const RIGHT_ROTATE: &[&str] = &[
+    "y = x.left",
+    "x.left = y.right",
+    "if y.right ≠ T.nil",
+    "    y.right.p = x",
+    "y.p = x.p",
+    "if x.p == T.nil",
+    "    T.root = y",
+    "elseif x == x.p.right",
+    "    x.p.right = y",
+    "else x.p.left = y",
+    "y.right = x",
+    "x.p = y",
+];
+
+const RB_DELETE: &[&str] = &[
+    "y = z",
+    "y-original-color = y.color",
+    "if z.left == T.nil",
+    "    x = z.right",
+    "    RB-TRANSPLANT(T, z, z.right)",
+    "elseif z.right == T.nil",
+    "    x = z.left",
+    "    RB-TRANSPLANT(T, z, z.left)",
+    "else y = TREE-MINIMUM(z.right)",
+    "    y-original-color = y.color",
+    "    x = y.right",
+    "    if y.p == z",
+    "        x.p = y",
+    "    else RB-TRANSPLANT(T, y, y.right)",
+    "        y.right = z.right",
+    "        y.right.p = y",
+    "    RB-TRANSPLANT(T, z, y)",
+    "    y.left = z.left",
+    "    y.left.p = y",
+    "    y.color = z.color",
+    "if y-original-color == BLACK",
+    "    RB-DELETE-FIXUP(T, x)",
+];
+
+const RB_DELETE_FIXUP: &[&str] = &[
+    "while x ≠ T.root and x.color == BLACK",
+    "    if x == x.p.left",
+    "        w = x.p.right",
+    "        if w.color == RED",
+    "            w.color = BLACK",
+    "            x.p.color = RED",
+    "            LEFT-ROTATE(T, x.p)",
+    "            w = x.p.right",
+    "        if w.left.color == BLACK and w.right.color == BLACK",
+    "            w.color = RED",
+    "            x = x.p",
+    "        else if w.right.color == BLACK",
+    "                w.left.color = BLACK",
+    "                w.color = RED",
+    "                RIGHT-ROTATE(T, w)",
+    "                w = x.p.right",
+    "            w.color = x.p.color",
+    "            x.p.color = BLACK",
+    "            w.right.color = BLACK",
+    "            LEFT-ROTATE(T, x.p)",
+    "            x = T.root",
+    "    else (same with \"right\" and \"left\" exchanged)",
+    "x.color = BLACK",
+];
+
+/// A CLRS procedure the engine's steps are mapped onto.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Procedure {
+    TreeSearch,
+    RbInsert,
+    RbInsertFixup,
+    LeftRotate,
+    RightRotate,
+    RbDelete,
+    RbDeleteFixup,
+}
+
+impl Procedure {
+    pub fn name(self) -> &'static str {
+        match self {
+            Procedure::TreeSearch => "ITERATIVE-TREE-SEARCH(x, k)",
+            Procedure::RbInsert => "RB-INSERT(T, z)",
+            Procedure::RbInsertFixup => "RB-INSERT-FIXUP(T, z)",
+            Procedure::LeftRotate => "LEFT-ROTATE(T, x)",
+            Procedure::RightRotate => "RIGHT-ROTATE(T, x)",
+            Procedure::RbDelete => "RB-DELETE(T, z)",
+            Procedure::RbDeleteFixup => "RB-DELETE-FIXUP(T, x)",
+        }
+    }
+
+    pub fn lines(self) -> &'static [&'static str] {
+        match self {
+            Procedure::TreeSearch => TREE_SEARCH,
+            Procedure::RbInsert => RB_INSERT,
+            Procedure::RbInsertFixup => RB_INSERT_FIXUP,
+            Procedure::LeftRotate => LEFT_ROTATE,
+            Procedure::RightRotate => RIGHT_ROTATE,
+            Procedure::RbDelete => RB_DELETE,
+            Procedure::RbDeleteFixup => RB_DELETE_FIXUP,
+        }
+    }
+}
+
+/// Where a recorded step sits in the pseudocode. Line numbers are 1-based, as in CLRS.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Highlight {
+    pub procedure: Procedure,
+    pub lines: Vec<usize>,
+    /// Pseudocode variable names for the nodes involved, e.g. `("z.p", parent)`.
+    pub roles: Vec<(&'static str, Pointer)>,
+    /// The step runs the branch with "right" and "left" exchanged.
+    pub mirrored: bool,
+    /// The fixup line a rotation was called from.
+    pub caller: Option<(Procedure, usize)>,
+}
+
+struct Active {
+    case: Case,
+    mirrored: bool,
+    roles: Vec<(&'static str, Pointer)>,
+}
+
+/// Maps every step of `trace` onto the pseudocode line it corresponds to.
+pub fn annotate(trace: &Trace) -> Vec<Highlight> {
+    let mut active: Option<Active> = None;
+    let mut highlights = Vec::with_capacity(trace.steps.len());
+
+    for step in &trace.steps {
+        let tree = &step.tree;
+        let highlight = match step.event {
+            Event::Visited { node } => visited(trace.operation, tree, node),
+            Event::Inserted { node } => {
+                let parent = tree[node].parent;
+                let lines = if parent.is_null() {
+                    vec![8, 9, 10]
+                } else if tree[parent].left == node {
+                    vec![8, 11, 12]
+                } else {
+                    vec![8, 13]
+                };
+                Highlight {
+                    procedure: Procedure::RbInsert,
+                    lines,
+                    roles: present(vec![("z", node), ("y", parent)]),
+                    mirrored: false,
+                    caller: None,
+                }
+            }
+            Event::Removed {
+                removal,
+                replacement,
+                ..
+            } => {
+                let (lines, role) = match removal {
+                    Removal::NoLeftChild => (vec![3, 4, 5], "x"),
+                    Removal::NoRightChild => (vec![6, 7, 8], "x"),
+                    Removal::Successor => (vec![9, 17, 18, 19, 20], "y"),
+                };
+                Highlight {
+                    procedure: Procedure::RbDelete,
+                    lines,
+                    roles: present(vec![(role, replacement)]),
+                    mirrored: false,
+                    caller: None,
+                }
+            }
+            Event::Case { case, node, parent } => {
+                let (mirrored, roles) = case_roles(tree, case, node, parent);
+                let (procedure, lines) = match case {
+                    Case::InsertRoot => (Procedure::RbInsertFixup, vec![1]),
+                    Case::InsertRedUncle => (Procedure::RbInsertFixup, vec![1, 2, 3, 4]),
+                    Case::InsertTriangle | Case::InsertLine => {
+                        (Procedure::RbInsertFixup, vec![4, 9])
+                    }
+                    Case::DeleteRedSibling => (Procedure::RbDeleteFixup, vec![1, 2, 3, 4]),
+                    Case::DeleteBlackNephews => (Procedure::RbDeleteFixup, vec![9]),
+                    Case::DeleteNearNephewRed | Case::DeleteFarNephewRed => {
+                        (Procedure::RbDeleteFixup, vec![9, 12])
+                    }
+                };
+                active = Some(Active {
+                    case,
+                    mirrored,
+                    roles: roles.clone(),
+                });
+                Highlight {
+                    procedure,
+                    lines,
+                    roles,
+                    mirrored,
+                    caller: None,
+                }
+            }
+            Event::CountChanged { node, .. } => count_changed(trace.operation, node),
+            Event::Recolored { node, .. } => recolored(trace.operation, active.as_ref(), node),
+            Event::RotatedLeft { node } | Event::RotatedRight { node } => {
+                let procedure = if matches!(step.event, Event::RotatedLeft { .. }) {
+                    Procedure::LeftRotate
+                } else {
+                    Procedure::RightRotate
+                };
+                let caller = active.as_ref().and_then(|active| match active.case {
+                    Case::InsertTriangle => Some((Procedure::RbInsertFixup, 11)),
+                    Case::InsertLine => Some((Procedure::RbInsertFixup, 14)),
+                    Case::DeleteRedSibling => Some((Procedure::RbDeleteFixup, 7)),
+                    Case::DeleteNearNephewRed => Some((Procedure::RbDeleteFixup, 15)),
+                    Case::DeleteFarNephewRed => Some((Procedure::RbDeleteFixup, 20)),
+                    _ => None,
+                });
+                Highlight {
+                    procedure,
+                    lines: (1..=procedure.lines().len()).collect(),
+                    roles: present(vec![("x", node), ("y", tree[node].parent)]),
+                    mirrored: active.as_ref().is_some_and(|active| active.mirrored),
+                    caller,
+                }
+            }
+        };
+        highlights.push(highlight);
+    }
+
+    highlights
+}
+
+fn present(roles: Vec<(&'static str, Pointer)>) -> Vec<(&'static str, Pointer)> {
+    roles
+        .into_iter()
+        .filter(|(_, pointer)| !pointer.is_null())
+        .collect()
+}
+
+fn visited(operation: Operation, tree: &RBTree, node: Pointer) -> Highlight {
+    let (procedure, lines) = match operation {
+        Operation::Insert(_) => (Procedure::RbInsert, vec![3, 4, 5]),
+        Operation::Delete(key) | Operation::Search(key) if tree[node].value == key => {
+            (Procedure::TreeSearch, vec![1, 5])
+        }
+        Operation::Delete(_) | Operation::Search(_) => (Procedure::TreeSearch, vec![1, 2]),
+    };
+    Highlight {
+        procedure,
+        lines,
+        roles: vec![("x", node)],
+        mirrored: false,
+        caller: None,
+    }
+}
+
+// A duplicate key bumps or drops a copy count instead of adding or splicing out a node: on
+// insert at the comparison that found the equal key, on delete where RB-DELETE starts on `z`.
+fn count_changed(operation: Operation, node: Pointer) -> Highlight {
+    let (procedure, lines, role) = match operation {
+        Operation::Insert(_) => (Procedure::RbInsert, vec![3, 5], "x"),
+        Operation::Delete(_) => (Procedure::RbDelete, vec![1], "z"),
+        Operation::Search(_) => (Procedure::TreeSearch, vec![5], "x"),
+    };
+    Highlight {
+        procedure,
+        lines,
+        roles: vec![(role, node)],
+        mirrored: false,
+        caller: None,
+    }
+}
+
+fn case_roles(
+    tree: &RBTree,
+    case: Case,
+    node: Pointer,
+    parent: Pointer,
+) -> (bool, Vec<(&'static str, Pointer)>) {
+    match case {
+        Case::InsertRoot => (false, vec![("z", node)]),
+        Case::InsertRedUncle | Case::InsertTriangle | Case::InsertLine => {
+            let grandparent = tree[parent].parent;
+            let mirrored = tree[grandparent].right == parent;
+            let mut roles = vec![("z", node), ("z.p", parent), ("z.p.p", grandparent)];
+            if case == Case::InsertRedUncle {
+                let uncle = if mirrored {
+                    tree[grandparent].left
+                } else {
+                    tree[grandparent].right
+                };
+                roles.push(("y", uncle));
+            }
+            (mirrored, present(roles))
+        }
+        _ => {
+            let mirrored = tree[parent].left != node;
+            let sibling = if mirrored {
+                tree[parent].left
+            } else {
+                tree[parent].right
+            };
+            let mut roles = vec![("x", node), ("x.p", parent), ("w", sibling)];
+            let (left, right) = (tree[sibling].left, tree[sibling].right);
+            let (near, far) = if mirrored {
+                (("w.right", right), ("w.left", left))
+            } else {
+                (("w.left", left), ("w.right", right))
+            };
+            match case {
+                Case::DeleteNearNephewRed => roles.push(near),
+                Case::DeleteFarNephewRed => roles.push(far),
+                _ => {}
+            }
+            (mirrored, present(roles))
+        }
+    }
+}
+
+fn recolored(operation: Operation, active: Option<&Active>, node: Pointer) -> Highlight {
+    let line = active.and_then(|active| {
+        let (role, _) = active.roles.iter().find(|(_, pointer)| *pointer == node)?;
+        let line = match (active.case, *role) {
+            (Case::InsertRoot, _) => 16,
+            (Case::InsertRedUncle, "z.p") => 5,
+            (Case::InsertRedUncle, "y") => 6,
+            (Case::InsertRedUncle, "z.p.p") => 7,
+            (Case::InsertLine, "z.p") => 12,
+            (Case::InsertLine, "z.p.p") => 13,
+            (Case::DeleteRedSibling, "w") => 5,
+            (Case::DeleteRedSibling, "x.p") => 6,
+            (Case::DeleteBlackNephews, "w") => 10,
+            (Case::DeleteNearNephewRed, "w.left" | "w.right") => 13,
+            (Case::DeleteNearNephewRed, "w") => 14,
+            (Case::DeleteFarNephewRed, "w") => 17,
+            (Case::DeleteFarNephewRed, "x.p") => 18,
+            (Case::DeleteFarNephewRed, "w.left" | "w.right") => 19,
+            _ => return None,
+        };
+        Some((line, active))
+    });
+
+    match (line, operation) {
+        (Some((line, active)), _) => Highlight {
+            procedure: if matches!(operation, Operation::Insert(_)) {
+                Procedure::RbInsertFixup
+            } else {
+                Procedure::RbDeleteFixup
+            },
+            lines: vec![line],
+            roles: active.roles.clone(),
+            mirrored: active.mirrored,
+            caller: None,
+        },
+        (None, Operation::Insert(_)) => Highlight {
+            procedure: Procedure::RbInsertFixup,
+            lines: vec![16],
+            roles: vec![("z", node)],
+            mirrored: false,
+            caller: None,
+        },
+        (None, _) => Highlight {
+            procedure: Procedure::RbDeleteFixup,
+            lines: vec![23],
+            roles: vec![("x", node)],
+            mirrored: false,
+            caller: None,
+        },
+    }
+}
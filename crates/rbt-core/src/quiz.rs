@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use crate::trace::{Case, Event, Operation};
+use crate::tree::{Color, Pointer, RBTree};
+
+/// A rotation, identified by the key of the node it pivots on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rotation {
+    Left(i32),
+    Right(i32),
+}
+
+impl Rotation {
+    /// Applies the rotation to the node holding its key, if there is one.
+    pub fn apply(self, tree: &mut RBTree) {
+        let (Rotation::Left(key) | Rotation::Right(key)) = self;
+        let node = tree.search(key);
+        if node.is_null() {
+            return;
+        }
+        match self {
+            Rotation::Left(_) => tree.rotate_left(node),
+            Rotation::Right(_) => tree.rotate_right(node),
+        }
+    }
+}
+
+/// "Predict the next state": inserting `key` into a tree, starting from the moment the new red
+/// leaf has been attached and before any fixup.
+#[derive(Clone, Debug)]
+pub struct Question {
+    pub key: i32,
+    /// The tree with `key` attached as a red leaf.
+    pub start: RBTree,
+    /// Fixup cases in the order they fire, possibly repeating. Blackening the root at the end is
+    /// left out: it is not a case to pick, and the final colors already show it.
+    pub cases: Vec<Case>,
+    pub rotations: Vec<Rotation>,
+    /// The tree once `RBTree::insert` has finished.
+    pub answer: RBTree,
+}
+
+/// How a prediction compares with what `RBTree::insert` does.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Grade {
+    /// The predicted set of cases matches the ones that fire.
+    pub cases: bool,
+    /// The predicted rotations match, in order.
+    pub rotations: bool,
+    /// Every node ended up under the right parent.
+    pub shape: bool,
+    /// Keys whose predicted color is wrong.
+    pub wrong_colors: Vec<i32>,
+}
+
+impl Grade {
+    pub fn is_correct(&self) -> bool {
+        self.cases && self.rotations && self.shape && self.wrong_colors.is_empty()
+    }
+}
+
+impl Question {
+    /// Sets up inserting `key` into a copy of `tree`. Returns `None` if `key` is already
+    /// present, since there would be nothing to predict.
+    pub fn new(tree: &RBTree, key: i32) -> Option<Question> {
+        if !tree.search(key).is_null() {
+            return None;
+        }
+
+        let mut answer = tree.snapshot();
+        let trace = answer.apply_traced(Operation::Insert(key));
+
+        let mut start = None;
+        let mut cases = Vec::new();
+        let mut rotations = Vec::new();
+        for step in &trace.steps {
+            match step.event {
+                Event::Inserted { .. } => start = Some(step.tree.clone()),
+                Event::Case {
+                    case: Case::InsertRoot,
+                    ..
+                } => {}
+                Event::Case { case, .. } => cases.push(case),
+                Event::RotatedLeft { node } => {
+                    rotations.push(Rotation::Left(step.tree[node].value))
+                }
+                Event::RotatedRight { node } => {
+                    rotations.push(Rotation::Right(step.tree[node].value))
+                }
+                _ => {}
+            }
+        }
+
+        Some(Question {
+            key,
+            start: start?,
+            cases,
+            rotations,
+            answer,
+        })
+    }
+
+    /// Compares the predicted cases and rotations, and the tree the learner ended up with, with
+    /// the real insertion.
+    pub fn grade(&self, cases: &[Case], rotations: &[Rotation], tree: &RBTree) -> Grade {
+        let expected = nodes(&self.answer);
+        let predicted = nodes(tree);
+        Grade {
+            cases: cases.iter().all(|case| self.cases.contains(case))
+                && self.cases.iter().all(|case| cases.contains(case)),
+            rotations: rotations == self.rotations.as_slice(),
+            shape: expected.len() == predicted.len()
+                && expected
+                    .iter()
+                    .all(|(key, (parent, _))| predicted.get(key).map(|(p, _)| p) == Some(parent)),
+            wrong_colors: expected
+                .iter()
+                .filter(|(key, (_, color))| predicted.get(key).map(|(_, c)| c) != Some(color))
+                .map(|(key, _)| *key)
+                .collect(),
+        }
+    }
+}
+
+// Every key with its parent's key and its color.
+fn nodes(tree: &RBTree) -> BTreeMap<i32, (Option<i32>, Color)> {
+    fn walk(tree: &RBTree, node: Pointer, nodes: &mut BTreeMap<i32, (Option<i32>, Color)>) {
+        if node.is_null() {
+            return;
+        }
+        let parent = tree[node].parent;
+        let parent = (!parent.is_null()).then(|| tree[parent].value);
+        nodes.insert(tree[node].value, (parent, tree[node].color));
+        walk(tree, tree[node].left, nodes);
+        walk(tree, tree[node].right, nodes);
+    }
+
+    let mut nodes = BTreeMap::new();
+    walk(tree, tree.root, &mut nodes);
+    nodes
+}
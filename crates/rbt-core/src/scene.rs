@@ -0,0 +1,223 @@
+use std::fmt::Display;
+
+use crate::augment::{Augmentation, Includes, Max, Min, Standard, Sum};
+use crate::layout::{Bounds, TreeLayout, LEVEL_GAP, NODE_RADIUS};
+use crate::tree::{Color, Pointer, RBTree};
+
+pub const RED_FILL: &str = "#ef4444";
+pub const RED_STROKE: &str = "#991b1b";
+pub const BLACK_FILL: &str = "#475569";
+pub const BLACK_STROKE: &str = "#1f2937";
+pub const EDGE_STROKE: &str = "#475569";
+pub const NIL_FILL: &str = "#111827";
+pub const NIL_SIZE: f32 = 6.0;
+pub const NIL_SPREAD: f32 = 6.0;
+pub const ANNOTATION_FILL: &str = "#334155";
+pub const ANNOTATION_SIZE: f32 = 4.0;
+/// Margin the exporters leave around everything drawn.
+pub const FRAME_PADDING: f32 = 10.0;
+// Advance per character in ems, wide enough for the raster font and common sans-serif faces.
+const CHAR_ADVANCE: f32 = 0.62;
+
+pub fn fill(color: Color) -> &'static str {
+    match color {
+        Color::Red => RED_FILL,
+        Color::Black => BLACK_FILL,
+    }
+}
+
+pub fn stroke(color: Color) -> &'static str {
+    match color {
+        Color::Red => RED_STROKE,
+        Color::Black => BLACK_STROKE,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeShape {
+    pub pointer: Pointer,
+    pub x: f32,
+    pub y: f32,
+    pub key: i32,
+    pub label: String,
+    pub color: Color,
+    /// Copies of the key held by the node, drawn as a badge when above 1.
+    pub count: u32,
+    pub annotation: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdgeShape {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+/// A NIL sentinel leaf, drawn as a small square hanging off `edge`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NilShape {
+    pub x: f32,
+    pub y: f32,
+    pub edge: EdgeShape,
+}
+
+/// Optional teaching aids layered on top of the plain tree.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SceneOptions {
+    pub nil_leaves: bool,
+    pub black_height: bool,
+    pub size: bool,
+    pub sum: bool,
+    /// Smallest and largest key in each subtree.
+    pub min_max: bool,
+    /// Each node's interval and the largest endpoint below it, for interval trees.
+    pub intervals: bool,
+}
+
+/// Drawing primitives for one tree state, shared by the canvas and the SVG exporter.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Scene {
+    pub nodes: Vec<NodeShape>,
+    pub edges: Vec<EdgeShape>,
+    pub nils: Vec<NilShape>,
+}
+
+impl Scene {
+    pub fn new(tree: &RBTree, layout: &TreeLayout) -> Self {
+        Scene::with_options(tree, layout, SceneOptions::default())
+    }
+
+    pub fn with_options(tree: &RBTree, layout: &TreeLayout, options: SceneOptions) -> Self {
+        let mut scene = Scene::default();
+        if !tree.root.is_null() {
+            scene.draw(tree, layout, options, tree.root);
+        }
+        scene
+    }
+
+    pub(crate) fn annotation(
+        tree: &RBTree,
+        options: SceneOptions,
+        pointer: Pointer,
+    ) -> Option<String> {
+        let mut parts = Vec::new();
+        if options.black_height {
+            parts.push(format!("bh={}", tree.black_height(pointer)));
+        }
+        if options.size {
+            parts.push(format!("n={}", tree[pointer].size));
+        }
+        if options.sum {
+            parts.push(Scene::aggregate::<Sum>(tree, pointer, "sum"));
+        }
+        if options.min_max {
+            parts.push(Scene::aggregate::<Min>(tree, pointer, "min"));
+            parts.push(Scene::aggregate::<Max>(tree, pointer, "max"));
+        }
+        if options.intervals {
+            let node = &tree[pointer];
+            parts.push(format!(
+                "[{}, {}] max={}",
+                node.value,
+                node.high,
+                tree.aggregate::<Max>(pointer)
+            ));
+        }
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(" "))
+        }
+    }
+
+    fn aggregate<A: Augmentation>(tree: &RBTree, pointer: Pointer, label: &str) -> String
+    where
+        Standard: Includes<A>,
+        A::Value: Display,
+    {
+        format!("{label}={}", tree.aggregate::<A>(pointer))
+    }
+
+    fn draw(
+        &mut self,
+        tree: &RBTree,
+        layout: &TreeLayout,
+        options: SceneOptions,
+        pointer: Pointer,
+    ) {
+        let Some(position) = layout.position(pointer) else {
+            return;
+        };
+
+        let node = &tree[pointer];
+        self.nodes.push(NodeShape {
+            pointer,
+            x: position.x,
+            y: position.y,
+            key: node.value,
+            label: node.value.to_string(),
+            color: node.color,
+            count: node.count,
+            annotation: Scene::annotation(tree, options, pointer),
+        });
+
+        for (child, offset) in [(node.left, -2.5), (node.right, 2.5)] {
+            if let Some(child_position) = layout.position(child) {
+                self.edges.push(EdgeShape {
+                    x1: position.x + offset,
+                    y1: position.y + 8.5,
+                    x2: child_position.x,
+                    y2: child_position.y,
+                });
+                self.draw(tree, layout, options, child);
+            } else if options.nil_leaves {
+                let x = position.x + offset.signum() * NIL_SPREAD;
+                let y = position.y + LEVEL_GAP * 0.75;
+                self.nils.push(NilShape {
+                    x,
+                    y,
+                    edge: EdgeShape {
+                        x1: position.x + offset,
+                        y1: position.y + 8.5,
+                        x2: x,
+                        y2: y - NIL_SIZE / 2.0,
+                    },
+                });
+            }
+        }
+    }
+}
+
+/// Estimated width of `text` drawn at `font_size`.
+pub fn text_width(text: &str, font_size: f32) -> f32 {
+    text.chars().count() as f32 * CHAR_ADVANCE * font_size
+}
+
+/// Canvas area covering the scene, including NIL leaves and annotations, plus padding.
+pub fn frame_bounds(scene: &Scene) -> Option<Bounds> {
+    let nodes = scene.nodes.iter().map(|node| {
+        let label = node.annotation.as_ref().map_or(0.0, |annotation| {
+            text_width(annotation, ANNOTATION_SIZE) + 1.0
+        });
+        let badge = if node.count > 1 {
+            text_width(&format!("×{}", node.count), 5.0) - 2.0
+        } else {
+            0.0
+        };
+        let circle = Bounds::around(node.x, node.y, NODE_RADIUS);
+        Bounds {
+            max_x: circle.max_x + label.max(badge),
+            max_y: circle.max_y + 3.0,
+            ..circle
+        }
+    });
+    let nils = scene
+        .nils
+        .iter()
+        .map(|nil| Bounds::around(nil.x, nil.y, NIL_SIZE / 2.0));
+    nodes
+        .chain(nils)
+        .reduce(Bounds::union)
+        .map(|bounds| bounds.padded(FRAME_PADDING))
+}
@@ -0,0 +1,87 @@
+use crate::trace::{Operation, Trace};
+use crate::tree::{Color, Pointer, RBTree};
+
+/// The shape of a tree at one moment.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeStats {
+    pub nodes: usize,
+    /// Nodes on the longest path from the root down to a NIL leaf.
+    pub height: u32,
+    /// Black height of the root; 0 for an empty tree.
+    pub black_height: u32,
+    pub red_nodes: usize,
+}
+
+impl TreeStats {
+    pub fn of(tree: &RBTree) -> TreeStats {
+        fn walk(tree: &RBTree, node: Pointer, depth: u32, stats: &mut TreeStats) {
+            if node.is_null() {
+                return;
+            }
+            stats.nodes += 1;
+            stats.height = stats.height.max(depth);
+            if tree[node].color == Color::Red {
+                stats.red_nodes += 1;
+            }
+            walk(tree, tree[node].left, depth + 1, stats);
+            walk(tree, tree[node].right, depth + 1, stats);
+        }
+
+        let mut stats = TreeStats::default();
+        walk(tree, tree.root, 1, &mut stats);
+        if !tree.is_empty() {
+            stats.black_height = tree.black_height(tree.root);
+        }
+        stats
+    }
+
+    /// The most a red-black tree with this many nodes can be: 2·log₂(n+1).
+    pub fn height_bound(&self) -> f64 {
+        2.0 * ((self.nodes + 1) as f64).log2()
+    }
+}
+
+/// The work one operation did and the tree it left behind.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OperationStats {
+    pub operation: Operation,
+    pub comparisons: usize,
+    pub rotations: usize,
+    pub recolorings: usize,
+    pub after: TreeStats,
+}
+
+impl OperationStats {
+    /// Counts the work in `trace`, which must have left the tree as `after`.
+    pub fn new(trace: &Trace, after: &RBTree) -> OperationStats {
+        OperationStats {
+            operation: trace.operation,
+            comparisons: trace.comparisons(),
+            rotations: trace.rotations(),
+            recolorings: trace.recolorings(),
+            after: TreeStats::of(after),
+        }
+    }
+}
+
+/// Work summed over a session of operations.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub operations: usize,
+    pub comparisons: usize,
+    pub rotations: usize,
+    pub recolorings: usize,
+}
+
+impl Totals {
+    pub fn of(stats: &[OperationStats]) -> Totals {
+        stats
+            .iter()
+            .fold(Totals::default(), |totals, stats| Totals {
+                operations: totals.operations + 1,
+                comparisons: totals.comparisons + stats.comparisons,
+                rotations: totals.rotations + stats.rotations,
+                recolorings: totals.recolorings + stats.recolorings,
+            })
+    }
+}
@@ -0,0 +1,110 @@
+use crate::augment::{Augmentation, Standard};
+use crate::tree::{Color, Pointer, RBTree};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Insert(i32),
+    Delete(i32),
+    Search(i32),
+}
+
+/// Which branch of a fixup loop is about to run. Insert cases follow CLRS: `InsertRedUncle`
+/// is case 1, `InsertTriangle` case 2 and `InsertLine` case 3; delete cases 1-4 likewise.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Case {
+    InsertRoot,
+    InsertRedUncle,
+    InsertTriangle,
+    InsertLine,
+    DeleteRedSibling,
+    DeleteBlackNephews,
+    DeleteNearNephewRed,
+    DeleteFarNephewRed,
+}
+
+/// How a deleted node was spliced out of the tree.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Removal {
+    NoLeftChild,
+    NoRightChild,
+    Successor,
+}
+
+/// A single step of an operation: a comparison, a structural change or a fixup decision.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    Visited {
+        node: Pointer,
+    },
+    Inserted {
+        node: Pointer,
+    },
+    Removed {
+        key: i32,
+        removal: Removal,
+        replacement: Pointer,
+    },
+    /// `node` is CLRS's `z` for inserts and `x` for deletes; it may be null in the latter,
+    /// which is why its `parent` is carried along.
+    Case {
+        case: Case,
+        node: Pointer,
+        parent: Pointer,
+    },
+    /// A duplicate key changed the node's `count` instead of the tree's shape.
+    CountChanged {
+        node: Pointer,
+        count: u32,
+    },
+    Recolored {
+        node: Pointer,
+        color: Color,
+    },
+    RotatedLeft {
+        node: Pointer,
+    },
+    RotatedRight {
+        node: Pointer,
+    },
+}
+
+/// An event together with the state of the tree right after it happened.
+#[derive(Clone, Debug)]
+pub struct Step<A: Augmentation = Standard> {
+    pub event: Event,
+    pub tree: RBTree<A>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Trace<A: Augmentation = Standard> {
+    pub operation: Operation,
+    pub steps: Vec<Step<A>>,
+}
+
+impl<A: Augmentation> Trace<A> {
+    pub fn rotations(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| {
+                matches!(
+                    step.event,
+                    Event::RotatedLeft { .. } | Event::RotatedRight { .. }
+                )
+            })
+            .count()
+    }
+
+    pub fn comparisons(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| matches!(step.event, Event::Visited { .. }))
+            .count()
+    }
+
+    pub fn recolorings(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| matches!(step.event, Event::Recolored { .. }))
+            .count()
+    }
+}
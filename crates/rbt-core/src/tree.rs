@@ -0,0 +1,822 @@
+use slab::Slab;
+use std::ops::{Index, IndexMut};
+
+use crate::augment::{Augmentation, Standard};
+use crate::trace::{Case, Event, Operation, Removal, Step, Trace};
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+pub struct Pointer(usize);
+
+impl Pointer {
+    #[inline]
+    pub fn null() -> Pointer {
+        Pointer(!0)
+    }
+
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        *self == Pointer::null()
+    }
+}
+
+impl<A: Augmentation> Index<Pointer> for RBTree<A> {
+    type Output = Node<A::Value>;
+
+    fn index(&self, index: Pointer) -> &Node<A::Value> {
+        &self.slab[index.0]
+    }
+}
+
+impl<A: Augmentation> IndexMut<Pointer> for RBTree<A> {
+    fn index_mut(&mut self, index: Pointer) -> &mut Node<A::Value> {
+        &mut self.slab[index.0]
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Black,
+}
+
+/// What inserting a key that is already present does.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Duplicates {
+    /// Leave the tree unchanged.
+    #[default]
+    Reject,
+    /// Bump the existing node's `count`.
+    Count,
+    /// Add another node; equal keys may end up on either side of each other after rotations.
+    Multiset,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node<V = <Standard as Augmentation>::Value> {
+    pub value: i32,
+    pub right: Pointer,
+    pub left: Pointer,
+    pub parent: Pointer,
+    pub color: Color,
+    /// Copies of `value` this node stands for; only above 1 under [`Duplicates::Count`].
+    pub count: u32,
+    /// Upper end of the interval `[value, high]` the node stands for; equal to `value` for
+    /// plain keys.
+    pub high: i32,
+    /// Keys in the subtree, counting copies; kept up to date by the tree.
+    pub size: u32,
+    /// The tree's augmentation for the subtree; see [`crate::augment`].
+    pub aggregate: V,
+}
+
+impl<V: Default> Node<V> {
+    /// A childless node holding one copy of `[value, high]`, carrying `A`'s value for it.
+    pub fn new<A: Augmentation<Value = V>>(
+        value: i32,
+        high: i32,
+        parent: Pointer,
+        color: Color,
+    ) -> Node<V> {
+        let mut node = Node {
+            value,
+            right: Pointer::null(),
+            left: Pointer::null(),
+            parent,
+            color,
+            count: 1,
+            high,
+            size: 1,
+            aggregate: V::default(),
+        };
+        node.aggregate = A::own(&node);
+        node
+    }
+}
+
+/// A red-black tree of `i32` keys carrying the augmentation `A` on every node.
+#[derive(Debug, Clone)]
+pub struct RBTree<A: Augmentation = Standard> {
+    pub slab: Slab<Node<A::Value>>,
+    pub root: Pointer,
+    duplicates: Duplicates,
+    recording: Option<Vec<Step<A>>>,
+}
+
+impl Default for RBTree {
+    fn default() -> Self {
+        RBTree::new()
+    }
+}
+
+impl RBTree {
+    pub fn new() -> Self {
+        RBTree::with_duplicates(Duplicates::default())
+    }
+
+    pub fn with_duplicates(duplicates: Duplicates) -> Self {
+        RBTree::augmented(duplicates)
+    }
+}
+
+impl<A: Augmentation> RBTree<A> {
+    /// An empty tree carrying another augmentation than the default, e.g.
+    /// `RBTree::<Sum>::augmented(Duplicates::Reject)`.
+    pub fn augmented(duplicates: Duplicates) -> Self {
+        RBTree {
+            slab: Slab::new(),
+            root: Pointer::null(),
+            duplicates,
+            recording: None,
+        }
+    }
+
+    pub fn duplicates(&self) -> Duplicates {
+        self.duplicates
+    }
+
+    pub fn clear_tree(&mut self) {
+        self.slab.clear();
+        self.root = Pointer::null();
+    }
+
+    /// The node behind `pointer`, if it is still part of the tree's storage.
+    pub fn get(&self, pointer: Pointer) -> Option<&Node<A::Value>> {
+        self.slab.get(pointer.0)
+    }
+
+    /// Number of keys stored, counting every copy.
+    pub fn len(&self) -> usize {
+        if self.root.is_null() {
+            0
+        } else {
+            self[self.root].size as usize
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_null()
+    }
+
+    /// Applies `operation` without recording intermediate states.
+    pub fn apply(&mut self, operation: Operation) {
+        match operation {
+            Operation::Insert(val) => self.insert(val),
+            Operation::Delete(val) => {
+                self.delete(val);
+            }
+            Operation::Search(val) => {
+                self.search_recorded(val);
+            }
+        }
+    }
+
+    /// Applies `operation` and returns every intermediate state it went through.
+    pub fn apply_traced(&mut self, operation: Operation) -> Trace<A> {
+        self.recording = Some(Vec::new());
+        self.apply(operation);
+        let steps = self.recording.take().unwrap_or_default();
+        Trace { operation, steps }
+    }
+
+    /// Copy of the current state with no recording attached.
+    pub fn snapshot(&self) -> RBTree<A> {
+        RBTree {
+            slab: self.slab.clone(),
+            root: self.root,
+            duplicates: self.duplicates,
+            recording: None,
+        }
+    }
+
+    pub(crate) fn alloc(&mut self, node: Node<A::Value>) -> Pointer {
+        Pointer(self.slab.insert(node))
+    }
+
+    fn record(&mut self, event: Event) {
+        if self.recording.is_some() {
+            let tree = self.snapshot();
+            if let Some(steps) = self.recording.as_mut() {
+                steps.push(Step { event, tree });
+            }
+        }
+    }
+
+    pub fn insert(&mut self, val: i32) {
+        self.insert_with_high(val, val);
+    }
+
+    /// Inserts a node keyed on `val` standing for the interval `[val, high]`.
+    pub(crate) fn insert_with_high(&mut self, val: i32, high: i32) {
+        if self.root.is_null() {
+            self.root = self.alloc(Node::new::<A>(val, high, Pointer::null(), Color::Black));
+            self.record(Event::Inserted { node: self.root });
+        } else {
+            let new_node = self.insert_node(val, high, self.root);
+            if !new_node.is_null() {
+                self.record(Event::Inserted { node: new_node });
+                self.insert_fixup(new_node);
+            }
+        }
+    }
+
+    /// Recomputes every node's aggregates bottom-up, for trees assembled node by node.
+    pub(crate) fn update_all(&mut self) {
+        fn update_recursive<A: Augmentation>(tree: &mut RBTree<A>, node: Pointer) {
+            if node.is_null() {
+                return;
+            }
+
+            update_recursive(tree, tree[node].left);
+            update_recursive(tree, tree[node].right);
+            tree.update_node(node);
+        }
+        update_recursive(self, self.root);
+    }
+
+    // Recomputes the aggregates of `node` from its children, which must be up to date.
+    fn update_node(&mut self, node: Pointer) {
+        self[node].size = self.recompute_size(node);
+        self[node].aggregate = self.recompute(node);
+    }
+
+    /// Recomputes the aggregates of `node` and every ancestor, after a change below them.
+    pub(crate) fn update_path(&mut self, mut node: Pointer) {
+        while !node.is_null() {
+            self.update_node(node);
+            node = self[node].parent;
+        }
+    }
+
+    // Updates the node a rotation moved up, whose old child is already up to date, and then its
+    // ancestors for as long as their values change. The rotated subtree keeps its keys, so
+    // aggregates of the keys alone stop at once; ones that depend on the shape, like height,
+    // can change further up.
+    fn update_rotated(&mut self, top: Pointer) {
+        self.update_node(top);
+        let mut node = self[top].parent;
+        while !node.is_null() {
+            let before = (self[node].size, self[node].aggregate);
+            self.update_node(node);
+            if (self[node].size, self[node].aggregate) == before {
+                break;
+            }
+            node = self[node].parent;
+        }
+    }
+
+    fn recolor(&mut self, node: Pointer, color: Color) {
+        if self[node].color != color {
+            self[node].color = color;
+            self.record(Event::Recolored { node, color });
+        }
+    }
+
+    fn insert_fixup(&mut self, node: Pointer) {
+        let parent = self[node].parent;
+        if self[node].parent.is_null() {
+            return self.insert_case1(node);
+        }
+
+        if self[parent].color == Color::Black {
+            return self.insert_case2(node);
+        }
+
+        let uncle = self.uncle(node);
+
+        if uncle.is_null() {
+            return self.insert_case4(node);
+        }
+        if self[uncle].color == Color::Black {
+            return self.insert_case4(node);
+        }
+
+        self.insert_case3(node)
+    }
+
+    fn mark(&mut self, case: Case, node: Pointer, parent: Pointer) {
+        self.record(Event::Case { case, node, parent });
+    }
+
+    fn insert_case1(&mut self, node: Pointer) {
+        self.mark(Case::InsertRoot, node, Pointer::null());
+        self.recolor(node, Color::Black);
+    }
+
+    fn insert_case2(&mut self, _node: Pointer) {}
+
+    fn insert_case3(&mut self, node: Pointer) {
+        let parent = self[node].parent;
+        let uncle = self.uncle(node);
+        let grandparent = self[parent].parent;
+
+        self.mark(Case::InsertRedUncle, node, parent);
+        self.recolor(parent, Color::Black);
+        self.recolor(uncle, Color::Black);
+        self.recolor(grandparent, Color::Red);
+
+        self.insert_fixup(grandparent);
+    }
+
+    fn insert_case4(&mut self, node: Pointer) {
+        let parent = self[node].parent;
+        let grandparent = self[parent].parent;
+
+        let parent_left = self[parent].left;
+        let parent_right = self[parent].right;
+
+        let grandparent_left = self[grandparent].left;
+        let grandparent_right = self[grandparent].right;
+
+        let mut n = node;
+
+        if n == parent_right && parent == grandparent_left {
+            self.mark(Case::InsertTriangle, n, parent);
+            self.rotate_left(parent);
+            n = self[n].left;
+        } else if n == parent_left && parent == grandparent_right {
+            self.mark(Case::InsertTriangle, n, parent);
+            self.rotate_right(parent);
+            n = self[n].right;
+        }
+
+        let parent = self[n].parent;
+        let grandparent = self[parent].parent;
+
+        let parent_left = self[parent].left;
+
+        self.mark(Case::InsertLine, n, parent);
+        self.recolor(parent, Color::Black);
+        self.recolor(grandparent, Color::Red);
+
+        if n == parent_left {
+            self.rotate_right(grandparent);
+        } else {
+            self.rotate_left(grandparent);
+        }
+    }
+
+    fn uncle(&self, node: Pointer) -> Pointer {
+        let parent = self[node].parent;
+        if parent.is_null() {
+            return Pointer::null();
+        }
+
+        let grandparent = self[parent].parent;
+
+        if grandparent.is_null() {
+            return Pointer::null();
+        }
+
+        let grandparent_left = self[grandparent].left;
+        let grandparent_right = self[grandparent].right;
+
+        if parent == grandparent_left {
+            return grandparent_right;
+        }
+
+        grandparent_left
+    }
+
+    /// Black nodes on the path from `node` down to a NIL leaf, not counting `node` itself
+    /// but counting the leaf. Follows left children, which is exact for a valid tree.
+    pub fn black_height(&self, node: Pointer) -> u32 {
+        let mut height = 1;
+        let mut current = self[node].left;
+        while !current.is_null() {
+            if self[current].color == Color::Black {
+                height += 1;
+            }
+            current = self[current].left;
+        }
+        height
+    }
+
+    pub fn search(&self, val: i32) -> Pointer {
+        let mut node = self.root;
+        while !node.is_null() && self[node].value != val {
+            node = if val < self[node].value {
+                self[node].left
+            } else {
+                self[node].right
+            };
+        }
+        node
+    }
+
+    fn search_recorded(&mut self, val: i32) -> Pointer {
+        let mut node = self.root;
+        while !node.is_null() {
+            self.record(Event::Visited { node });
+            if self[node].value == val {
+                break;
+            }
+            node = if val < self[node].value {
+                self[node].left
+            } else {
+                self[node].right
+            };
+        }
+        node
+    }
+
+    pub fn minimum(&self, mut node: Pointer) -> Pointer {
+        while !self[node].left.is_null() {
+            node = self[node].left;
+        }
+        node
+    }
+
+    pub fn maximum(&self, mut node: Pointer) -> Pointer {
+        while !self[node].right.is_null() {
+            node = self[node].right;
+        }
+        node
+    }
+
+    /// In-order predecessor of `node`, or null if it holds the smallest key.
+    pub fn predecessor(&self, node: Pointer) -> Pointer {
+        if !self[node].left.is_null() {
+            return self.maximum(self[node].left);
+        }
+        let mut child = node;
+        let mut parent = self[node].parent;
+        while !parent.is_null() && self[parent].left == child {
+            child = parent;
+            parent = self[parent].parent;
+        }
+        parent
+    }
+
+    /// In-order successor of `node`, or null if it holds the largest key.
+    pub fn successor(&self, node: Pointer) -> Pointer {
+        if !self[node].right.is_null() {
+            return self.minimum(self[node].right);
+        }
+        let mut child = node;
+        let mut parent = self[node].parent;
+        while !parent.is_null() && self[parent].right == child {
+            child = parent;
+            parent = self[parent].parent;
+        }
+        parent
+    }
+
+    /// Number of edges between `node` and the root.
+    pub fn depth(&self, node: Pointer) -> u32 {
+        let mut depth = 0;
+        let mut current = self[node].parent;
+        while !current.is_null() {
+            depth += 1;
+            current = self[current].parent;
+        }
+        depth
+    }
+
+    /// 1-based position of `node` in in-order traversal, read off the subtree sizes. Copies
+    /// counted on a node all share its rank; the next key comes after all of them.
+    pub fn rank(&self, node: Pointer) -> u32 {
+        let size = |pointer: Pointer| {
+            if pointer.is_null() {
+                0
+            } else {
+                self[pointer].size
+            }
+        };
+
+        let mut rank = size(self[node].left) + 1;
+        let mut current = node;
+        while !self[current].parent.is_null() {
+            let parent = self[current].parent;
+            if self[parent].right == current {
+                rank += size(self[parent].left) + self[parent].count;
+            }
+            current = parent;
+        }
+        rank
+    }
+
+    /// How many copies of `val` the tree holds under any duplicate policy.
+    pub fn count(&self, val: i32) -> u32 {
+        fn count_in<A: Augmentation>(tree: &RBTree<A>, node: Pointer, val: i32) -> u32 {
+            if node.is_null() {
+                return 0;
+            }
+            let current = &tree[node];
+            if val < current.value {
+                count_in(tree, current.left, val)
+            } else if val > current.value {
+                count_in(tree, current.right, val)
+            } else {
+                current.count
+                    + count_in(tree, current.left, val)
+                    + count_in(tree, current.right, val)
+            }
+        }
+        count_in(self, self.root, val)
+    }
+
+    /// Nodes compared while searching for `val`, ending at the match if there is one.
+    pub fn search_path(&self, val: i32) -> Vec<Pointer> {
+        self.search_path_from(self.root, val)
+    }
+
+    /// Like [`RBTree::search_path`], but starting at `node` and staying inside its subtree.
+    pub fn search_path_from(&self, mut node: Pointer, val: i32) -> Vec<Pointer> {
+        let mut path = Vec::new();
+        while !node.is_null() {
+            path.push(node);
+            if self[node].value == val {
+                break;
+            }
+            node = if val < self[node].value {
+                self[node].left
+            } else {
+                self[node].right
+            };
+        }
+        path
+    }
+
+    fn is_black(&self, node: Pointer) -> bool {
+        node.is_null() || self[node].color == Color::Black
+    }
+
+    /// Removes `val` from the tree, returning whether it was present.
+    pub fn delete(&mut self, val: i32) -> bool {
+        let node = self.search_recorded(val);
+        if node.is_null() {
+            return false;
+        }
+        self.remove_node(node);
+        true
+    }
+
+    /// Removes one copy of the key held by `node`.
+    pub(crate) fn remove_node(&mut self, node: Pointer) {
+        let val = self[node].value;
+        if self[node].count > 1 {
+            self[node].count -= 1;
+            let count = self[node].count;
+            self.update_path(node);
+            self.record(Event::CountChanged { node, count });
+            return;
+        }
+
+        let mut removed_color = self[node].color;
+        let child;
+        let child_parent;
+        let removal;
+        let replacement;
+
+        if self[node].left.is_null() {
+            child = self[node].right;
+            child_parent = self[node].parent;
+            self.transplant(node, child);
+            removal = Removal::NoLeftChild;
+            replacement = child;
+        } else if self[node].right.is_null() {
+            child = self[node].left;
+            child_parent = self[node].parent;
+            self.transplant(node, child);
+            removal = Removal::NoRightChild;
+            replacement = child;
+        } else {
+            let successor = self.minimum(self[node].right);
+            removal = Removal::Successor;
+            replacement = successor;
+            removed_color = self[successor].color;
+            child = self[successor].right;
+
+            if self[successor].parent == node {
+                child_parent = successor;
+            } else {
+                child_parent = self[successor].parent;
+                self.transplant(successor, child);
+                let right = self[node].right;
+                self[successor].right = right;
+                self[right].parent = successor;
+            }
+
+            self.transplant(node, successor);
+            let left = self[node].left;
+            self[successor].left = left;
+            self[left].parent = successor;
+            self[successor].color = self[node].color;
+        }
+
+        self.slab.remove(node.0);
+        self.update_path(child_parent);
+        self.record(Event::Removed {
+            key: val,
+            removal,
+            replacement,
+        });
+
+        if removed_color == Color::Black {
+            self.delete_fixup(child, child_parent);
+        }
+    }
+
+    fn transplant(&mut self, old: Pointer, new: Pointer) {
+        let parent = self[old].parent;
+        if parent.is_null() {
+            self.root = new;
+        } else if self[parent].left == old {
+            self[parent].left = new;
+        } else {
+            self[parent].right = new;
+        }
+
+        if !new.is_null() {
+            self[new].parent = parent;
+        }
+    }
+
+    // `node` may be null, so its parent is tracked separately.
+    fn delete_fixup(&mut self, mut node: Pointer, mut parent: Pointer) {
+        while node != self.root && self.is_black(node) {
+            if self[parent].left == node {
+                let mut sibling = self[parent].right;
+                if !self.is_black(sibling) {
+                    self.mark(Case::DeleteRedSibling, node, parent);
+                    self.recolor(sibling, Color::Black);
+                    self.recolor(parent, Color::Red);
+                    self.rotate_left(parent);
+                    sibling = self[parent].right;
+                }
+
+                if self.is_black(self[sibling].left) && self.is_black(self[sibling].right) {
+                    self.mark(Case::DeleteBlackNephews, node, parent);
+                    self.recolor(sibling, Color::Red);
+                    node = parent;
+                    parent = self[node].parent;
+                } else {
+                    if self.is_black(self[sibling].right) {
+                        self.mark(Case::DeleteNearNephewRed, node, parent);
+                        let sibling_left = self[sibling].left;
+                        self.recolor(sibling_left, Color::Black);
+                        self.recolor(sibling, Color::Red);
+                        self.rotate_right(sibling);
+                        sibling = self[parent].right;
+                    }
+
+                    self.mark(Case::DeleteFarNephewRed, node, parent);
+                    let parent_color = self[parent].color;
+                    let sibling_right = self[sibling].right;
+                    self.recolor(sibling, parent_color);
+                    self.recolor(parent, Color::Black);
+                    self.recolor(sibling_right, Color::Black);
+                    self.rotate_left(parent);
+                    node = self.root;
+                }
+            } else {
+                let mut sibling = self[parent].left;
+                if !self.is_black(sibling) {
+                    self.mark(Case::DeleteRedSibling, node, parent);
+                    self.recolor(sibling, Color::Black);
+                    self.recolor(parent, Color::Red);
+                    self.rotate_right(parent);
+                    sibling = self[parent].left;
+                }
+
+                if self.is_black(self[sibling].left) && self.is_black(self[sibling].right) {
+                    self.mark(Case::DeleteBlackNephews, node, parent);
+                    self.recolor(sibling, Color::Red);
+                    node = parent;
+                    parent = self[node].parent;
+                } else {
+                    if self.is_black(self[sibling].left) {
+                        self.mark(Case::DeleteNearNephewRed, node, parent);
+                        let sibling_right = self[sibling].right;
+                        self.recolor(sibling_right, Color::Black);
+                        self.recolor(sibling, Color::Red);
+                        self.rotate_left(sibling);
+                        sibling = self[parent].left;
+                    }
+
+                    self.mark(Case::DeleteFarNephewRed, node, parent);
+                    let parent_color = self[parent].color;
+                    let sibling_left = self[sibling].left;
+                    self.recolor(sibling, parent_color);
+                    self.recolor(parent, Color::Black);
+                    self.recolor(sibling_left, Color::Black);
+                    self.rotate_right(parent);
+                    node = self.root;
+                }
+            }
+        }
+
+        if !node.is_null() {
+            self.recolor(node, Color::Black);
+        }
+    }
+
+    fn insert_node(&mut self, val: i32, high: i32, node: Pointer) -> Pointer {
+        self.record(Event::Visited { node });
+        let node_value = self[node].value;
+        let left = self[node].left;
+        let right = self[node].right;
+
+        if val == node_value && self.duplicates != Duplicates::Multiset {
+            if self.duplicates == Duplicates::Count {
+                self[node].count += 1;
+                let count = self[node].count;
+                self.update_path(node);
+                self.record(Event::CountChanged { node, count });
+            }
+            Pointer::null()
+        } else if val >= node_value {
+            if right.is_null() {
+                self[node].right = self.alloc(Node::new::<A>(val, high, node, Color::Red));
+                self.update_path(node);
+                self[node].right
+            } else {
+                self.insert_node(val, high, right)
+            }
+        } else if left.is_null() {
+            self[node].left = self.alloc(Node::new::<A>(val, high, node, Color::Red));
+            self.update_path(node);
+            self[node].left
+        } else {
+            self.insert_node(val, high, left)
+        }
+    }
+
+    /// Rotates left at `current`, moving its right child up into its place. Subtree aggregates
+    /// stay correct; does nothing if the right child is NIL.
+    pub fn rotate_left(&mut self, current: Pointer) {
+        let right = self[current].right;
+
+        if right.is_null() {
+            return;
+        }
+
+        let right_left = self[right].left;
+        let parent = self[current].parent;
+
+        self[current].right = right_left;
+
+        if !right_left.is_null() {
+            self[right_left].parent = current;
+        }
+
+        self[current].parent = right;
+        self[right].left = current;
+
+        self[right].parent = parent;
+
+        if parent.is_null() {
+            self.root = right;
+        } else {
+            if self[parent].right == current {
+                self[parent].right = right;
+            } else {
+                self[parent].left = right;
+            }
+        }
+
+        self.update_node(current);
+        self.update_rotated(right);
+
+        self.record(Event::RotatedLeft { node: current });
+    }
+
+    /// Rotates right at `current`, moving its left child up into its place. Subtree aggregates
+    /// stay correct; does nothing if the left child is NIL.
+    pub fn rotate_right(&mut self, current: Pointer) {
+        let left = self[current].left;
+
+        if left.is_null() {
+            return;
+        }
+
+        let left_right = self[left].right;
+        let parent = self[current].parent;
+
+        self[current].left = left_right;
+
+        if !left_right.is_null() {
+            self[left_right].parent = current;
+        }
+
+        self[current].parent = left;
+        self[left].right = current;
+
+        self[left].parent = parent;
+
+        if parent.is_null() {
+            self.root = left;
+        } else {
+            if self[parent].left == current {
+                self[parent].left = left;
+            } else {
+                self[parent].right = left;
+            }
+        }
+
+        self.update_node(current);
+        self.update_rotated(left);
+
+        self.record(Event::RotatedRight { node: current });
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::layout::Bounds;
+
+pub const MIN_WIDTH: f32 = 20.0;
+pub const FIT_PADDING: f32 = 20.0;
+pub const FIT_MIN_SIZE: f32 = 300.0;
+
+/// The SVG `viewBox` of the canvas, in layout coordinates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ViewBox {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Default for ViewBox {
+    fn default() -> Self {
+        ViewBox::new(-50.0, -20.0, 300.0, 300.0)
+    }
+}
+
+impl fmt::Display for ViewBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.x, self.y, self.width, self.height)
+    }
+}
+
+impl ViewBox {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        ViewBox {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Scales the box by `factor` (below 1 zooms in) keeping `(cx, cy)` at the same spot.
+    pub fn zoom(&self, factor: f32, cx: f32, cy: f32) -> ViewBox {
+        let factor = factor.max(MIN_WIDTH / self.width);
+        ViewBox {
+            x: cx - (cx - self.x) * factor,
+            y: cy - (cy - self.y) * factor,
+            width: self.width * factor,
+            height: self.height * factor,
+        }
+    }
+
+    /// Linear interpolation towards `target`, `t` running from 0 to 1.
+    pub fn lerp(&self, target: ViewBox, t: f32) -> ViewBox {
+        let mix = |from: f32, to: f32| from + (to - from) * t;
+        ViewBox {
+            x: mix(self.x, target.x),
+            y: mix(self.y, target.y),
+            width: mix(self.width, target.width),
+            height: mix(self.height, target.height),
+        }
+    }
+
+    pub fn center_on(&self, cx: f32, cy: f32) -> ViewBox {
+        ViewBox {
+            x: cx - self.width / 2.0,
+            y: cy - self.height / 2.0,
+            ..*self
+        }
+    }
+
+    pub fn pan(&self, dx: f32, dy: f32) -> ViewBox {
+        ViewBox {
+            x: self.x + dx,
+            y: self.y + dy,
+            ..*self
+        }
+    }
+
+    /// Smallest box around `bounds` with some padding, centred and never smaller than
+    /// [`FIT_MIN_SIZE`] so small trees are not blown up.
+    pub fn fit(bounds: Bounds) -> ViewBox {
+        let width = (bounds.width() + 2.0 * FIT_PADDING).max(FIT_MIN_SIZE);
+        let height = (bounds.height() + 2.0 * FIT_PADDING).max(FIT_MIN_SIZE);
+        let cx = (bounds.min_x + bounds.max_x) / 2.0;
+        let cy = (bounds.min_y + bounds.max_y) / 2.0;
+        ViewBox::new(cx - width / 2.0, cy - height / 2.0, width, height)
+    }
+
+    /// View-box units per screen pixel for an element of the given size, assuming the
+    /// default `preserveAspectRatio="xMidYMid meet"`.
+    pub fn units_per_pixel(&self, element_width: f32, element_height: f32) -> f32 {
+        if element_width <= 0.0 || element_height <= 0.0 {
+            return 1.0;
+        }
+        (self.width / element_width).max(self.height / element_height)
+    }
+
+    /// Converts a point in element pixels to layout coordinates.
+    pub fn to_layout(
+        &self,
+        px: f32,
+        py: f32,
+        element_width: f32,
+        element_height: f32,
+    ) -> (f32, f32) {
+        let scale = self.units_per_pixel(element_width, element_height);
+        let left = self.x - (element_width * scale - self.width) / 2.0;
+        let top = self.y - (element_height * scale - self.height) / 2.0;
+        (left + px * scale, top + py * scale)
+    }
+}
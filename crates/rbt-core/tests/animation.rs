@@ -0,0 +1,60 @@
+use rbt_core::export::animation::{encode, AnimationError, AnimationFormat, AnimationOptions};
+use rbt_core::{Operation, RBTree};
+
+fn steps(keys: &[i32]) -> Vec<RBTree> {
+    let mut tree = RBTree::new();
+    keys.iter()
+        .flat_map(|&key| tree.apply_traced(Operation::Insert(key)).steps)
+        .map(|step| step.tree)
+        .collect()
+}
+
+#[test]
+fn gif_has_one_frame_per_step_of_the_same_size() {
+    let trees = steps(&[10, 20, 30, 15]);
+    let options = AnimationOptions {
+        delay_ms: 250,
+        ..AnimationOptions::default()
+    };
+    let bytes = encode(&trees, options).unwrap();
+
+    let mut decoder = gif::DecodeOptions::new()
+        .read_info(bytes.as_slice())
+        .unwrap();
+    let (width, height) = (decoder.width(), decoder.height());
+    let mut delays = Vec::new();
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!((frame.width, frame.height), (width, height));
+        delays.push(frame.delay);
+    }
+    assert_eq!(delays.len(), trees.len());
+    assert_eq!(delays[0], 25);
+    assert!(delays[delays.len() - 1] > 25);
+}
+
+#[test]
+fn apng_declares_every_frame_and_respects_max_width() {
+    let trees = steps(&(0..12).collect::<Vec<_>>());
+    let options = AnimationOptions {
+        format: AnimationFormat::Apng,
+        max_width: 200,
+        ..AnimationOptions::default()
+    };
+    let bytes = encode(&trees, options).unwrap();
+
+    let reader = png::Decoder::new(bytes.as_slice()).read_info().unwrap();
+    let info = reader.info();
+    assert!(info.width <= 200);
+    assert_eq!(
+        info.animation_control.unwrap().num_frames as usize,
+        trees.len()
+    );
+}
+
+#[test]
+fn empty_trees_are_skipped() {
+    assert!(matches!(
+        encode(&[RBTree::new()], AnimationOptions::default()),
+        Err(AnimationError::NoFrames)
+    ));
+}
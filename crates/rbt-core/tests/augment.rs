@@ -0,0 +1,129 @@
+use proptest::prelude::*;
+use rbt_core::augment::{Augmentation, Max, Min, Sum};
+use rbt_core::invariants::Violation;
+use rbt_core::{Duplicates, Node, Operation, Pointer, RBTree};
+
+fn operations() -> impl Strategy<Value = Vec<Operation>> {
+    let key = -32i32..32;
+    prop::collection::vec(
+        prop_oneof![
+            3 => key.clone().prop_map(Operation::Insert),
+            2 => key.prop_map(Operation::Delete),
+        ],
+        0..100,
+    )
+}
+
+fn is_aggregate(violation: &Violation) -> bool {
+    matches!(
+        violation,
+        Violation::Size { .. } | Violation::Aggregate { .. }
+    )
+}
+
+/// Levels below and including the node, to plug in a tree-shaped aggregate of our own.
+#[derive(Clone, Debug)]
+struct Height;
+
+impl Augmentation for Height {
+    type Value = u32;
+
+    fn own<V>(_: &Node<V>) -> u32 {
+        1
+    }
+
+    fn combine(own: u32, left: Option<u32>, right: Option<u32>) -> u32 {
+        own + left.unwrap_or(0).max(right.unwrap_or(0))
+    }
+}
+
+fn height<A: Augmentation>(tree: &RBTree<A>, node: Pointer) -> u32 {
+    if node.is_null() {
+        0
+    } else {
+        1 + height(tree, tree[node].left).max(height(tree, tree[node].right))
+    }
+}
+
+#[test]
+fn root_aggregates_summarise_the_whole_tree() {
+    let mut tree = RBTree::with_duplicates(Duplicates::Count);
+    for key in [10, 5, 20, 5, -3, 15] {
+        tree.insert(key);
+    }
+    let root = tree.root;
+    assert_eq!(tree[root].size, 6);
+    assert_eq!(tree.aggregate::<Sum>(root), 52);
+    assert_eq!(tree.aggregate::<Min>(root), -3);
+    assert_eq!(tree.aggregate::<Max>(root), 20);
+
+    tree.delete(5);
+    tree.delete(-3);
+    let root = tree.root;
+    assert_eq!(tree.aggregate::<Sum>(root), 50);
+    assert_eq!(tree.aggregate::<Min>(root), 5);
+}
+
+#[test]
+fn hand_rotations_keep_aggregates() {
+    let mut tree = RBTree::new();
+    for key in 1..=15 {
+        tree.insert(key);
+    }
+    for _ in 0..3 {
+        tree.rotate_right(tree.root);
+    }
+    tree.rotate_left(tree.root);
+    assert!(!tree.violations().iter().any(is_aggregate));
+    assert_eq!(tree.aggregate::<Sum>(tree.root), 120);
+}
+
+#[test]
+fn custom_augmentations_plug_into_the_tree() {
+    let mut tree = RBTree::<(Height, Sum)>::augmented(Duplicates::Reject);
+    for key in 1..=20 {
+        tree.insert(key);
+    }
+    tree.delete(8);
+    assert_eq!(tree.aggregate::<(Height, Sum)>(tree.root).1, 202);
+    assert_eq!(
+        tree.aggregate::<(Height, Sum)>(tree.root).0,
+        height(&tree, tree.root)
+    );
+
+    let mut tree = RBTree::<Height>::augmented(Duplicates::Reject);
+    tree.insert(1);
+    assert_eq!(tree.aggregate::<Height>(tree.root), 1);
+    assert_eq!(tree[tree.root].size, 1);
+}
+
+proptest! {
+    // Snapshots are no longer recounted, so every intermediate state shows what the tree
+    // maintained by itself.
+    #[test]
+    fn every_recorded_step_is_up_to_date(operations in operations()) {
+        for duplicates in [Duplicates::Reject, Duplicates::Count, Duplicates::Multiset] {
+            let mut tree = RBTree::with_duplicates(duplicates);
+            for &operation in &operations {
+                let trace = tree.apply_traced(operation);
+                for step in &trace.steps {
+                    let stale: Vec<_> =
+                        step.tree.violations().into_iter().filter(is_aggregate).collect();
+                    prop_assert!(stale.is_empty(), "{:?} after {:?}", stale, step.event);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn plugged_in_height_matches_the_shape(operations in operations()) {
+        let mut tree = RBTree::<Height>::augmented(Duplicates::Multiset);
+        for &operation in &operations {
+            tree.apply(operation);
+            prop_assert!(tree.validate().is_ok());
+            if !tree.is_empty() {
+                prop_assert_eq!(tree.aggregate::<Height>(tree.root), height(&tree, tree.root));
+            }
+        }
+    }
+}
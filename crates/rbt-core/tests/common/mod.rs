@@ -0,0 +1,10 @@
+use rbt_core::RBTree;
+
+/// A tree built by inserting `keys` in order.
+pub fn tree(keys: &[i32]) -> RBTree {
+    let mut tree = RBTree::new();
+    for &key in keys {
+        tree.insert(key);
+    }
+    tree
+}
@@ -0,0 +1,146 @@
+mod common;
+
+use common::tree;
+use rbt_core::{Case, Color, Event, Operation, Removal, Trace};
+
+fn cases(trace: &Trace) -> Vec<Case> {
+    trace
+        .steps
+        .iter()
+        .filter_map(|step| match step.event {
+            Event::Case { case, .. } => Some(case),
+            _ => None,
+        })
+        .collect()
+}
+
+fn removal(trace: &Trace) -> (Removal, i32) {
+    trace
+        .steps
+        .iter()
+        .find_map(|step| match step.event {
+            Event::Removed {
+                removal,
+                replacement,
+                ..
+            } => Some((removal, step.tree[replacement].value)),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn nodes_with_one_child_are_replaced_by_it() {
+    let mut tree = tree(&[20, 10]);
+    let trace = tree.apply_traced(Operation::Delete(20));
+    assert_eq!(removal(&trace), (Removal::NoRightChild, 10));
+    assert_eq!(tree[tree.root].value, 10);
+    assert_eq!(tree[tree.root].color, Color::Black);
+
+    let mut tree = self::tree(&[10, 20]);
+    let trace = tree.apply_traced(Operation::Delete(10));
+    assert_eq!(removal(&trace), (Removal::NoLeftChild, 20));
+    assert!(tree.validate().is_ok());
+}
+
+#[test]
+fn nodes_with_two_children_are_replaced_by_their_successor() {
+    // 30 is 20's right child; in the second tree 60 sits deeper, below 70.
+    let mut tree = tree(&[10, 20, 30]);
+    let trace = tree.apply_traced(Operation::Delete(20));
+    assert_eq!(removal(&trace), (Removal::Successor, 30));
+    assert_eq!(tree.keys().collect::<Vec<_>>(), [10, 30]);
+    assert!(tree.validate().is_ok());
+
+    let mut tree = self::tree(&[50, 30, 70, 60, 80]);
+    let trace = tree.apply_traced(Operation::Delete(50));
+    assert_eq!(removal(&trace), (Removal::Successor, 60));
+    assert_eq!(tree[tree.root].value, 60);
+    assert_eq!(tree.keys().collect::<Vec<_>>(), [30, 60, 70, 80]);
+    assert!(tree.validate().is_ok());
+}
+
+#[test]
+fn removing_a_red_node_needs_no_fixup() {
+    let mut tree = tree(&[10, 20]);
+    let trace = tree.apply_traced(Operation::Delete(20));
+    assert!(cases(&trace).is_empty());
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn missing_keys_leave_the_tree_alone() {
+    let mut tree = tree(&[10, 20, 30]);
+    let trace = tree.apply_traced(Operation::Delete(25));
+    assert!(trace
+        .steps
+        .iter()
+        .all(|step| matches!(step.event, Event::Visited { .. })));
+    assert_eq!(tree.keys().collect::<Vec<_>>(), [10, 20, 30]);
+}
+
+// Each row removes a black node with at most one child, so the replacement `x` is a left child,
+// and runs the fixup through the listed cases. Negating the keys builds the mirror image, which
+// has to take the same cases with `x` on the right.
+#[test]
+fn every_fixup_case_restores_the_invariants_on_both_sides() {
+    let rows: [(&[i32], i32, &[Case]); 4] = [
+        (
+            &[10, 20, 30, 40, 50, 60, 70, 80],
+            10,
+            &[Case::DeleteBlackNephews],
+        ),
+        (&[10, 20, 30, 40], 10, &[Case::DeleteFarNephewRed]),
+        (
+            &[40, 80, 10, 50],
+            10,
+            &[Case::DeleteNearNephewRed, Case::DeleteFarNephewRed],
+        ),
+        (
+            &[10, 20, 30, 40, 50, 60],
+            10,
+            &[Case::DeleteRedSibling, Case::DeleteBlackNephews],
+        ),
+    ];
+    for (keys, key, expected) in rows {
+        for sign in [1, -1] {
+            let keys: Vec<i32> = keys.iter().map(|&key| key * sign).collect();
+            let mut tree = tree(&keys);
+            let trace = tree.apply_traced(Operation::Delete(key * sign));
+            assert_eq!(
+                cases(&trace),
+                expected,
+                "deleting {} from {keys:?}",
+                key * sign
+            );
+
+            let on_left = trace.steps.iter().find_map(|step| match step.event {
+                Event::Case { node, parent, .. } => Some(step.tree[parent].left == node),
+                _ => None,
+            });
+            assert_eq!(
+                on_left,
+                Some(sign == 1),
+                "deleting {} from {keys:?}",
+                key * sign
+            );
+
+            assert!(tree.validate().is_ok());
+            assert_eq!(tree.count(key * sign), 0);
+            assert_eq!(tree.len(), keys.len() - 1);
+        }
+    }
+}
+
+#[test]
+fn a_black_successor_leaves_its_old_spot_to_the_fixup() {
+    let mut tree = tree(&[60, 50, 40, 30, 20, 10]);
+    let trace = tree.apply_traced(Operation::Delete(50));
+    assert_eq!(removal(&trace).0, Removal::Successor);
+    assert_eq!(
+        cases(&trace),
+        [Case::DeleteRedSibling, Case::DeleteBlackNephews]
+    );
+    assert!(tree.validate().is_ok());
+    assert_eq!(tree.keys().collect::<Vec<_>>(), [10, 20, 30, 40, 60]);
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::tree;
+use rbt_core::edit::{EditError, Side};
+use rbt_core::invariants::Violation;
+use rbt_core::Color;
+
+#[test]
+fn attached_leaf_waits_for_a_fixup() {
+    let mut tree = tree(&[10, 5, 20, 25]);
+    let leaf = tree.attach_leaf(30).unwrap();
+    assert_eq!(tree[leaf].color, Color::Red);
+    assert_eq!(tree[tree[leaf].parent].value, 25);
+    assert_eq!(tree.len(), 5);
+    assert_eq!(
+        tree.violations(),
+        [Violation::RedRed { node: leaf }],
+        "only the red-red rule should break"
+    );
+    assert_eq!(tree.attach_leaf(25), Err(EditError::Duplicate));
+}
+
+#[test]
+fn fixing_up_by_hand_gives_a_valid_tree() {
+    let mut tree = tree(&[10, 5, 20, 25]);
+    tree.attach_leaf(30).unwrap();
+    let (parent, grandparent) = (tree.search(25), tree.search(20));
+    tree.toggle_color(parent);
+    tree.toggle_color(grandparent);
+    tree.rotate_left(grandparent);
+    assert_eq!(tree.validate(), Ok(()));
+}
+
+#[test]
+fn reattach_refuses_taken_slots_and_cycles() {
+    let mut tree = tree(&[10, 5, 20, 15, 25]);
+    let (five, twenty, fifteen) = (tree.search(5), tree.search(20), tree.search(15));
+    assert_eq!(
+        tree.reattach(five, twenty, Side::Left),
+        Err(EditError::Occupied)
+    );
+    assert_eq!(
+        tree.reattach(twenty, fifteen, Side::Left),
+        Err(EditError::Cycle)
+    );
+    assert_eq!(
+        tree.reattach(tree.root, fifteen, Side::Left),
+        Err(EditError::Cycle)
+    );
+}
+
+#[test]
+fn reattach_keeps_sizes_and_reports_order() {
+    let mut tree = tree(&[10, 5, 20, 15, 25]);
+    let (five, fifteen) = (tree.search(5), tree.search(15));
+    tree.reattach(fifteen, five, Side::Right).unwrap();
+    assert_eq!(tree[fifteen].parent, five);
+    assert_eq!(tree[five].size, 2);
+    let violations = tree.violations();
+    assert!(violations.contains(&Violation::Order { node: fifteen }));
+    assert!(violations
+        .iter()
+        .all(|violation| !matches!(violation, Violation::Size { .. })));
+    assert_eq!(Violation::Order { node: fifteen }.node(), Some(fifteen));
+}
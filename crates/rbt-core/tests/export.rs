@@ -0,0 +1,129 @@
+mod common;
+
+use std::collections::{BTreeSet, HashMap};
+
+use common::tree;
+use rbt_core::export::dot::{to_dot, to_dot_with};
+use rbt_core::export::svg::to_svg_with;
+use rbt_core::export::tikz::{to_tikz, Package};
+use rbt_core::scene::{text_width, ANNOTATION_SIZE};
+use rbt_core::{RBTree, SceneOptions};
+
+// The visible `parent -> child` edges between keyed nodes, as pairs of keys. Invisible edges and
+// edges to NIL boxes or the invisible spacers, which carry no key, are left out.
+fn dot_edges(dot: &str) -> BTreeSet<(i32, i32)> {
+    let keys: HashMap<&str, i32> = dot
+        .lines()
+        .filter_map(|line| {
+            let (id, attributes) = line.trim().split_once(" [label=\"")?;
+            let key = attributes.split(['"', '×']).next()?.parse().ok()?;
+            Some((id, key))
+        })
+        .collect();
+    dot.lines()
+        .filter(|line| !line.contains("style=invis"))
+        .filter_map(|line| {
+            let (from, to) = line.trim().split_once(" -> ")?;
+            let to = to.trim_end_matches(';').split(' ').next()?;
+            Some((*keys.get(from)?, *keys.get(to)?))
+        })
+        .collect()
+}
+
+fn tree_edges(tree: &RBTree) -> BTreeSet<(i32, i32)> {
+    tree.slab
+        .iter()
+        .filter(|(_, node)| !node.parent.is_null())
+        .map(|(_, node)| (tree[node.parent].value, node.value))
+        .collect()
+}
+
+#[test]
+fn dot_has_one_filled_node_per_key_and_an_edge_per_child() {
+    let tree = tree(&[10, 5, 20, 30]);
+    let dot = to_dot(&tree);
+    assert!(dot.starts_with("digraph rbtree {"));
+    assert_eq!(dot.matches(r##"fillcolor="#ef4444""##).count(), 1);
+    assert_eq!(dot.matches(r##"fillcolor="#475569""##).count(), 3);
+    assert_eq!(dot_edges(&dot), tree_edges(&tree));
+    assert_eq!(dot_edges(&dot).len(), 3);
+}
+
+#[test]
+fn dot_nil_leaves_replace_placeholders() {
+    let options = SceneOptions {
+        nil_leaves: true,
+        black_height: true,
+        ..SceneOptions::default()
+    };
+    let dot = to_dot_with(&tree(&[10, 5]), options);
+    assert_eq!(dot.matches("shape=box").count(), 3);
+    assert!(!dot.contains("invis"));
+    assert!(dot.contains(r#"xlabel="bh=1""#));
+}
+
+#[test]
+fn forest_keeps_an_only_child_on_its_side() {
+    let tex = to_tikz(&tree(&[10, 20]), Package::Forest, SceneOptions::default());
+    let body = &tex[tex.find("\\begin{forest}").unwrap()..];
+    let child = body.find("{20}").unwrap();
+    let phantom = body.find("[, phantom]").unwrap();
+    assert!(phantom < child);
+    assert!(tex.contains("\\definecolor{rbred}{HTML}{ef4444}"));
+}
+
+#[test]
+fn qtree_brackets_balance() {
+    let tex = to_tikz(
+        &tree(&[50, 25, 75, 10, 30, 60, 90, 5]),
+        Package::Qtree,
+        SceneOptions::default(),
+    );
+    let body = &tex[tex.find("\\Tree").unwrap()..];
+    assert_eq!(body.matches('[').count(), body.matches(']').count());
+    assert_eq!(body.matches("\\node[rb ").count(), 8);
+}
+
+fn attr(element: &str, name: &str) -> f32 {
+    let start = element.find(&format!(" {name}=\"")).unwrap() + name.len() + 3;
+    let end = start + element[start..].find('"').unwrap();
+    element[start..end].parse().unwrap()
+}
+
+#[test]
+fn svg_view_box_covers_nil_leaves_and_annotations() {
+    let options = SceneOptions {
+        nil_leaves: true,
+        black_height: true,
+        size: true,
+        ..SceneOptions::default()
+    };
+    let svg = to_svg_with(&tree(&[10, 5, 15]), options);
+    let view_box = svg.split("viewBox=\"").nth(1).unwrap();
+    let view_box: Vec<f32> = view_box[..view_box.find('"').unwrap()]
+        .split(' ')
+        .map(|n| n.parse().unwrap())
+        .collect();
+    let (min_x, min_y) = (view_box[0], view_box[1]);
+    let (max_x, max_y) = (min_x + view_box[2], min_y + view_box[3]);
+
+    let rects: Vec<&str> = svg.lines().filter(|l| l.starts_with("<rect")).collect();
+    assert_eq!(rects.len(), 4);
+    for rect in rects {
+        let (x, y) = (attr(rect, "x"), attr(rect, "y"));
+        assert!(x >= min_x && x + attr(rect, "width") <= max_x, "{rect}");
+        assert!(y >= min_y && y + attr(rect, "height") <= max_y, "{rect}");
+    }
+
+    let labels: Vec<&str> = svg.lines().filter(|l| l.contains("bh=")).collect();
+    assert_eq!(labels.len(), 3);
+    for label in labels {
+        let text = &label[label.find('>').unwrap() + 1..label.find("</text>").unwrap()];
+        let (x, y) = (attr(label, "x"), attr(label, "y"));
+        assert!(
+            x >= min_x && x + text_width(text, ANNOTATION_SIZE) <= max_x,
+            "{label}"
+        );
+        assert!(y - ANNOTATION_SIZE >= min_y && y <= max_y, "{label}");
+    }
+}
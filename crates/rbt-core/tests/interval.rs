@@ -0,0 +1,93 @@
+use rbt_core::augment::Max;
+use rbt_core::interval::{Interval, IntervalTree};
+use rbt_core::invariants::Violation;
+
+// The example tree of CLRS figure 14.4.
+fn clrs() -> IntervalTree {
+    let mut tree = IntervalTree::new();
+    for (lo, hi) in [
+        (16, 21),
+        (8, 9),
+        (25, 30),
+        (5, 8),
+        (15, 23),
+        (17, 19),
+        (26, 26),
+        (0, 3),
+        (6, 10),
+        (19, 20),
+    ] {
+        tree.insert(Interval::new(lo, hi));
+    }
+    tree
+}
+
+fn intervals(tree: &IntervalTree, nodes: &[rbt_core::Pointer]) -> Vec<Interval> {
+    nodes.iter().map(|&node| tree.interval(node)).collect()
+}
+
+#[test]
+fn max_is_kept_through_rotations() {
+    let tree = clrs();
+    assert!(tree.tree().validate().is_ok());
+    let root = tree.tree().root;
+    assert_eq!(tree.tree().aggregate::<Max>(root), 30);
+
+    // A bare rotation has no full recount afterwards to hide behind.
+    let mut rotated = tree.tree().clone();
+    rotated.rotate_left(root);
+    rotated.rotate_right(rotated.root);
+    rotated.rotate_right(rotated.root);
+    assert!(rotated.violations().iter().all(|violation| !matches!(
+        violation,
+        Violation::Aggregate { .. } | Violation::Size { .. }
+    )));
+
+    let mut ascending = IntervalTree::new();
+    for lo in 0..50 {
+        ascending.insert(Interval::new(lo, 100 - lo));
+        assert!(ascending.tree().validate().is_ok());
+    }
+}
+
+#[test]
+fn search_finds_an_overlap_or_nothing() {
+    let tree = clrs();
+    let (node, path) = tree.search(Interval::new(22, 25));
+    assert!(tree.interval(node).overlaps(Interval::new(22, 25)));
+    assert_eq!(path.last(), Some(&node));
+
+    let (node, _) = tree.search(Interval::new(11, 14));
+    assert!(node.is_null());
+}
+
+#[test]
+fn overlapping_lists_every_match_in_order() {
+    let tree = clrs();
+    let found = tree.overlapping(Interval::new(8, 16));
+    assert_eq!(
+        intervals(&tree, &found),
+        [
+            Interval::new(5, 8),
+            Interval::new(6, 10),
+            Interval::new(8, 9),
+            Interval::new(15, 23),
+            Interval::new(16, 21),
+        ]
+    );
+    assert!(tree.overlapping(Interval::new(31, 40)).is_empty());
+}
+
+#[test]
+fn remove_takes_out_the_exact_interval() {
+    let mut tree = clrs();
+    tree.insert(Interval::new(16, 40));
+    assert!(!tree.remove(Interval::new(16, 17)));
+    assert!(tree.remove(Interval::new(16, 40)));
+    assert!(tree.tree().validate().is_ok());
+    assert_eq!(tree.tree().aggregate::<Max>(tree.tree().root), 30);
+    assert!(tree.remove(Interval::new(25, 30)));
+    assert_eq!(tree.tree().aggregate::<Max>(tree.tree().root), 26);
+    assert_eq!(tree.len(), 9);
+    assert!(tree.tree().validate().is_ok());
+}
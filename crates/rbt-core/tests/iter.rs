@@ -0,0 +1,65 @@
+mod common;
+
+use common::tree;
+use rbt_core::{Duplicates, RBTree};
+
+fn values<'a>(nodes: impl Iterator<Item = (rbt_core::Pointer, &'a rbt_core::Node)>) -> Vec<i32> {
+    nodes.map(|(_, node)| node.value).collect()
+}
+
+#[test]
+fn iterates_in_order_from_both_ends() {
+    let tree = tree(&[50, 30, 70, 20, 40, 60, 80, 10]);
+    assert_eq!(values(tree.iter()), [10, 20, 30, 40, 50, 60, 70, 80]);
+    assert_eq!(values(tree.iter().rev()), [80, 70, 60, 50, 40, 30, 20, 10]);
+
+    // The two ends stop when they meet, without handing out a node twice.
+    let mut iter = tree.iter();
+    assert_eq!(values(iter.by_ref().take(3)), [10, 20, 30]);
+    assert_eq!(values(iter.rev()), [80, 70, 60, 50, 40]);
+    assert_eq!(RBTree::new().iter().count(), 0);
+}
+
+#[test]
+fn range_honours_every_kind_of_bound() {
+    let tree = tree(&(1..=20).map(|key| key * 5).collect::<Vec<_>>());
+    assert_eq!(values(tree.range(20..35)), [20, 25, 30]);
+    assert_eq!(values(tree.range(21..=35)), [25, 30, 35]);
+    assert_eq!(values(tree.range(..12)), [5, 10]);
+    assert_eq!(values(tree.range(96..)), [100]);
+    assert_eq!(values(tree.range(20..35).rev()), [30, 25, 20]);
+    assert_eq!(tree.range(41..44).count(), 0);
+    assert_eq!(tree.range(200..).count(), 0);
+    assert_eq!(tree.range(..).count(), 20);
+}
+
+#[test]
+fn neighbour_queries_work_for_absent_keys() {
+    let tree = tree(&[10, 20, 30, 40]);
+    let key = |pointer: rbt_core::Pointer| (!pointer.is_null()).then(|| tree[pointer].value);
+    assert_eq!(key(tree.first()), Some(10));
+    assert_eq!(key(tree.last()), Some(40));
+    assert_eq!(key(tree.floor(25)), Some(20));
+    assert_eq!(key(tree.floor(30)), Some(30));
+    assert_eq!(key(tree.floor(5)), None);
+    assert_eq!(key(tree.ceiling(25)), Some(30));
+    assert_eq!(key(tree.ceiling(41)), None);
+    assert_eq!(key(tree.lower(30)), Some(20));
+    assert_eq!(key(tree.higher(30)), Some(40));
+    assert_eq!(key(tree.higher(40)), None);
+    assert!(RBTree::new().first().is_null());
+}
+
+#[test]
+fn duplicates_are_enumerated_under_every_policy() {
+    for duplicates in [Duplicates::Count, Duplicates::Multiset] {
+        let mut tree = RBTree::with_duplicates(duplicates);
+        for key in [5, 3, 5, 8, 5, 1, 5] {
+            tree.insert(key);
+        }
+        assert_eq!(tree.keys().collect::<Vec<_>>(), [1, 3, 5, 5, 5, 5, 8]);
+        assert_eq!(tree.keys().rev().collect::<Vec<_>>(), [8, 5, 5, 5, 5, 3, 1]);
+        let fives: u32 = tree.range(5..=5).map(|(_, node)| node.count).sum();
+        assert_eq!(fives, 4);
+    }
+}
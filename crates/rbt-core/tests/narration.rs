@@ -0,0 +1,44 @@
+use rbt_core::narration::narrate;
+use rbt_core::{Operation, RBTree};
+
+fn narrate_after(keys: &[i32], operation: Operation) -> Vec<String> {
+    let mut tree = RBTree::new();
+    for &key in keys {
+        tree.insert(key);
+    }
+    narrate(&tree.apply_traced(operation))
+}
+
+#[test]
+fn one_sentence_per_step() {
+    let mut tree = RBTree::new();
+    for key in (0..50).map(|i| (i * 29) % 53) {
+        let trace = tree.apply_traced(Operation::Insert(key));
+        assert_eq!(narrate(&trace).len(), trace.steps.len());
+    }
+    for key in (0..50).step_by(3) {
+        let trace = tree.apply_traced(Operation::Delete(key));
+        assert_eq!(narrate(&trace).len(), trace.steps.len());
+    }
+}
+
+#[test]
+fn red_uncle_names_all_three_nodes() {
+    let sentences = narrate_after(&[10, 8, 12], Operation::Insert(9));
+    assert!(sentences.contains(
+        &"Uncle 12 is red → recolor parent 8 and uncle 12 black, grandparent 10 red (case 1)"
+            .to_string()
+    ));
+}
+
+#[test]
+fn missing_key_ends_at_nil() {
+    let sentences = narrate_after(&[10, 8, 12], Operation::Search(11));
+    assert_eq!(
+        sentences,
+        [
+            "Compare 11 with 10: go right",
+            "Compare 11 with 12: the left child is NIL, so 11 is not in the tree",
+        ]
+    );
+}
@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use proptest::prelude::*;
+use rbt_core::export::{history, json};
+use rbt_core::invariants::Violation;
+use rbt_core::{Duplicates, Operation, Pointer, RBTree};
+
+fn operation() -> impl Strategy<Value = Operation> {
+    let key = -32i32..32;
+    prop_oneof![
+        3 => key.clone().prop_map(Operation::Insert),
+        2 => key.clone().prop_map(Operation::Delete),
+        1 => key.prop_map(Operation::Search),
+    ]
+}
+
+fn operations() -> impl Strategy<Value = Vec<Operation>> {
+    prop::collection::vec(operation(), 0..200)
+}
+
+fn in_order_nodes(tree: &RBTree) -> Vec<Pointer> {
+    fn walk(tree: &RBTree, node: Pointer, nodes: &mut Vec<Pointer>) {
+        if node.is_null() {
+            return;
+        }
+        walk(tree, tree[node].left, nodes);
+        nodes.push(node);
+        walk(tree, tree[node].right, nodes);
+    }
+
+    let mut nodes = Vec::new();
+    walk(tree, tree.root, &mut nodes);
+    nodes
+}
+
+// Keys in order, repeating counted keys once per copy.
+fn in_order(tree: &RBTree) -> Vec<i32> {
+    in_order_nodes(tree)
+        .into_iter()
+        .flat_map(|node| std::iter::repeat_n(tree[node].value, tree[node].count as usize))
+        .collect()
+}
+
+fn apply_to_oracle(oracle: &mut BTreeSet<i32>, operation: Operation) {
+    match operation {
+        Operation::Insert(key) => {
+            oracle.insert(key);
+        }
+        Operation::Delete(key) => {
+            oracle.remove(&key);
+        }
+        Operation::Search(_) => {}
+    }
+}
+
+proptest! {
+    #[test]
+    fn matches_btreeset_after_every_step(operations in operations()) {
+        let mut tree = RBTree::new();
+        let mut oracle = BTreeSet::new();
+
+        for operation in operations {
+            match operation {
+                Operation::Delete(key) => prop_assert_eq!(tree.delete(key), oracle.contains(&key)),
+                Operation::Search(key) => {
+                    prop_assert_eq!(!tree.search(key).is_null(), oracle.contains(&key))
+                }
+                Operation::Insert(_) => tree.apply(operation),
+            }
+            apply_to_oracle(&mut oracle, operation);
+
+            prop_assert_eq!(tree.violations(), Vec::<Violation>::new());
+            prop_assert_eq!(in_order(&tree), oracle.iter().copied().collect::<Vec<i32>>());
+            prop_assert_eq!(tree.len(), oracle.len());
+        }
+    }
+
+    #[test]
+    fn traced_and_untraced_runs_agree(operations in operations()) {
+        let mut plain = RBTree::new();
+        let mut traced = RBTree::new();
+
+        for operation in operations {
+            plain.apply(operation);
+            let trace = traced.apply_traced(operation);
+
+            for step in &trace.steps {
+                let keys = in_order(&step.tree);
+                prop_assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+            }
+            if let Some(last) = trace.steps.last() {
+                prop_assert_eq!(last.tree.violations(), Vec::<Violation>::new());
+            }
+            prop_assert_eq!(in_order(&plain), in_order(&traced));
+        }
+    }
+
+    #[test]
+    fn json_snapshots_round_trip(operations in operations()) {
+        let mut tree = RBTree::new();
+        for operation in operations {
+            tree.apply(operation);
+        }
+
+        let restored = json::from_json(&json::to_json(&tree)).unwrap();
+        prop_assert_eq!(json::to_json(&restored), json::to_json(&tree));
+    }
+
+    #[test]
+    fn history_round_trips(operations in operations()) {
+        prop_assert_eq!(history::decode(&history::encode(&operations)).unwrap(), operations);
+    }
+}
+
+#[test]
+fn sorted_inserts_then_deletes_stay_balanced() {
+    let mut tree = RBTree::new();
+    for key in 0..1000 {
+        tree.insert(key);
+        assert_eq!(tree.validate(), Ok(()));
+    }
+    assert_eq!(tree.len(), 1000);
+
+    for key in (0..1000).rev().step_by(2) {
+        assert!(tree.delete(key));
+        assert_eq!(tree.validate(), Ok(()));
+    }
+    assert_eq!(in_order(&tree), (0..1000).step_by(2).collect::<Vec<i32>>());
+}
+
+#[test]
+fn duplicate_insert_only_compares() {
+    let mut tree = RBTree::new();
+    tree.insert(5);
+    let trace = tree.apply_traced(Operation::Insert(5));
+    assert_eq!(trace.steps.len(), trace.comparisons());
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn black_height_counts_nil_leaves() {
+    let mut tree = RBTree::new();
+    for key in [10, 5, 15] {
+        tree.insert(key);
+    }
+    assert_eq!(tree.black_height(tree.root), 1);
+
+    tree.insert(1);
+    assert_eq!(tree.black_height(tree.root), 2);
+    assert_eq!(tree.black_height(tree.search(1)), 1);
+}
+
+proptest! {
+    #[test]
+    fn duplicate_policies_match_a_counting_oracle(
+        duplicates in prop_oneof![Just(Duplicates::Count), Just(Duplicates::Multiset)],
+        operations in operations(),
+    ) {
+        let mut tree = RBTree::with_duplicates(duplicates);
+        let mut oracle: BTreeMap<i32, u32> = BTreeMap::new();
+
+        for operation in operations {
+            match operation {
+                Operation::Insert(key) => {
+                    tree.insert(key);
+                    *oracle.entry(key).or_default() += 1;
+                }
+                Operation::Delete(key) => {
+                    prop_assert_eq!(tree.delete(key), oracle.contains_key(&key));
+                    if let Some(count) = oracle.get_mut(&key) {
+                        *count -= 1;
+                        if *count == 0 {
+                            oracle.remove(&key);
+                        }
+                    }
+                }
+                Operation::Search(key) => {
+                    prop_assert_eq!(tree.count(key), oracle.get(&key).copied().unwrap_or(0));
+                }
+            }
+
+            let expected: Vec<i32> = oracle
+                .iter()
+                .flat_map(|(&key, &count)| std::iter::repeat_n(key, count as usize))
+                .collect();
+            prop_assert_eq!(tree.violations(), Vec::<Violation>::new());
+            prop_assert_eq!(in_order(&tree), expected);
+            prop_assert_eq!(tree.len(), oracle.values().sum::<u32>() as usize);
+
+            let mut position = 1;
+            for node in in_order_nodes(&tree) {
+                prop_assert_eq!(tree.rank(node), position);
+                position += tree[node].count;
+            }
+        }
+    }
+
+    #[test]
+    fn order_queries_match_btreeset(keys in prop::collection::vec(0i32..500, 1..100)) {
+        let mut tree = RBTree::new();
+        for &key in &keys {
+            tree.insert(key);
+        }
+        let sorted: Vec<i32> = keys.iter().copied().collect::<BTreeSet<i32>>().into_iter().collect();
+
+        for (index, &key) in sorted.iter().enumerate() {
+            let node = tree.search(key);
+            prop_assert_eq!(tree.rank(node) as usize, index + 1);
+
+            let predecessor = tree.predecessor(node);
+            let successor = tree.successor(node);
+            prop_assert_eq!(
+                (!predecessor.is_null()).then(|| tree[predecessor].value),
+                index.checked_sub(1).map(|i| sorted[i])
+            );
+            prop_assert_eq!(
+                (!successor.is_null()).then(|| tree[successor].value),
+                sorted.get(index + 1).copied()
+            );
+
+            let path = tree.search_path(key);
+            prop_assert_eq!(path.len() as u32, tree.depth(node) + 1);
+            prop_assert_eq!(path.last().copied(), Some(node));
+            for (i, &start) in path.iter().enumerate() {
+                prop_assert_eq!(&tree.search_path_from(start, key), &path[i..]);
+            }
+        }
+    }
+}
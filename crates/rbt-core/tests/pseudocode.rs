@@ -0,0 +1,80 @@
+use rbt_core::pseudocode::annotate;
+use rbt_core::{Duplicates, Event, Operation, Procedure, RBTree};
+
+fn run(keys: &[i32], operation: Operation) -> (RBTree, rbt_core::Trace) {
+    let mut tree = RBTree::new();
+    for &key in keys {
+        tree.insert(key);
+    }
+    let trace = tree.apply_traced(operation);
+    (tree, trace)
+}
+
+#[test]
+fn every_step_maps_to_a_line() {
+    let keys: Vec<i32> = (0..64).map(|i| (i * 37) % 101).collect();
+    let mut tree = RBTree::new();
+    let mut operations: Vec<Operation> = keys.iter().map(|&k| Operation::Insert(k)).collect();
+    operations.extend(keys.iter().step_by(2).map(|&k| Operation::Delete(k)));
+    operations.push(Operation::Search(keys[1]));
+
+    for operation in operations {
+        let trace = tree.apply_traced(operation);
+        let highlights = annotate(&trace);
+        assert_eq!(highlights.len(), trace.steps.len());
+        for highlight in highlights {
+            let count = highlight.procedure.lines().len();
+            assert!(!highlight.lines.is_empty());
+            assert!(highlight
+                .lines
+                .iter()
+                .all(|&line| (1..=count).contains(&line)));
+            assert!(highlight.roles.iter().all(|(_, node)| !node.is_null()));
+        }
+    }
+}
+
+#[test]
+fn line_case_rotation_comes_from_line_14() {
+    let (_, trace) = run(&[1, 2], Operation::Insert(3));
+    let highlights = annotate(&trace);
+    let rotation = highlights
+        .iter()
+        .find(|highlight| highlight.procedure == Procedure::LeftRotate)
+        .unwrap();
+    assert_eq!(rotation.caller, Some((Procedure::RbInsertFixup, 14)));
+    assert!(rotation.mirrored);
+}
+
+#[test]
+fn red_uncle_recolors_lines_5_to_7() {
+    let (_, trace) = run(&[2, 1, 3], Operation::Insert(4));
+    let lines: Vec<usize> = annotate(&trace)
+        .into_iter()
+        .filter(|highlight| highlight.procedure == Procedure::RbInsertFixup)
+        .flat_map(|highlight| highlight.lines)
+        .collect();
+    assert_eq!(lines, [1, 2, 3, 4, 5, 6, 7, 1, 16]);
+}
+
+#[test]
+fn copy_counts_map_to_the_procedure_that_changed_them() {
+    let mut tree = RBTree::with_duplicates(Duplicates::Count);
+    for key in [10, 5, 15] {
+        tree.insert(key);
+    }
+    for (operation, procedure, role) in [
+        (Operation::Insert(5), Procedure::RbInsert, "x"),
+        (Operation::Delete(5), Procedure::RbDelete, "z"),
+    ] {
+        let trace = tree.apply_traced(operation);
+        let (_, highlight) = trace
+            .steps
+            .iter()
+            .zip(annotate(&trace))
+            .find(|(step, _)| matches!(step.event, Event::CountChanged { .. }))
+            .unwrap();
+        assert_eq!(highlight.procedure, procedure);
+        assert_eq!(highlight.roles, [(role, tree.search(5))]);
+    }
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::tree;
+use rbt_core::invariants::Violation;
+use rbt_core::quiz::{Question, Rotation};
+use rbt_core::{Case, Color, RBTree};
+
+// Replays the real answer on the learner's board: the rotations, then every final color.
+fn solve(question: &Question) -> RBTree {
+    let mut board = question.start.clone();
+    for rotation in &question.rotations {
+        rotation.apply(&mut board);
+    }
+    let pointers: Vec<_> = board.slab.iter().map(|(i, _)| i).collect();
+    for i in pointers {
+        let key = board.slab[i].value;
+        board.slab[i].color = question.answer[question.answer.search(key)].color;
+    }
+    board
+}
+
+#[test]
+fn start_is_the_attached_red_leaf() {
+    let question = Question::new(&tree(&[10, 5, 20]), 25).unwrap();
+    let leaf = question.start.search(25);
+    assert_eq!(question.start[leaf].color, Color::Red);
+    assert_eq!(question.start[question.start[leaf].parent].value, 20);
+    assert!(question.answer.validate().is_ok());
+    assert!(Question::new(&tree(&[10]), 10).is_none());
+}
+
+#[test]
+fn replaying_the_answer_is_graded_correct() {
+    for key in [1, 7, 12, 30] {
+        let question = Question::new(&tree(&[10, 5, 20, 15, 25, 3]), key).unwrap();
+        let grade = question.grade(&question.cases, &question.rotations, &solve(&question));
+        assert!(grade.is_correct(), "{key}: {grade:?}");
+    }
+}
+
+#[test]
+fn line_case_rotates_the_grandparent() {
+    let question = Question::new(&tree(&[10, 20]), 30).unwrap();
+    assert_eq!(question.cases, [Case::InsertLine]);
+    assert_eq!(question.rotations, [Rotation::Left(10)]);
+
+    let grade = question.grade(&[Case::InsertLine], &[], &question.start);
+    assert!(grade.cases);
+    assert!(!grade.rotations);
+    assert!(!grade.shape);
+    assert_eq!(grade.wrong_colors, [10, 20]);
+}
+
+#[test]
+fn manual_rotations_keep_sizes_and_order() {
+    let mut tree = tree(&(0..31).collect::<Vec<_>>());
+    for key in [15, 7, 23, 3, 0, 30] {
+        Rotation::Left(key).apply(&mut tree);
+        Rotation::Right(key).apply(&mut tree);
+        Rotation::Right(key).apply(&mut tree);
+    }
+    assert!(tree.violations().iter().all(|violation| matches!(
+        violation,
+        Violation::RedRoot | Violation::RedRed { .. } | Violation::BlackHeight { .. }
+    )));
+    assert_eq!(tree.len(), 31);
+}
+
+#[test]
+fn recoloring_up_to_the_root_needs_no_root_case() {
+    // The red uncle 5 is recolored, which leaves the root 10 red until it is blackened again.
+    let question = Question::new(&tree(&[10, 5, 20]), 25).unwrap();
+    assert_eq!(question.cases, [Case::InsertRedUncle]);
+
+    let grade = question.grade(&[Case::InsertRedUncle], &[], &solve(&question));
+    assert!(grade.is_correct(), "{grade:?}");
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::tree;
+use rbt_core::stats::{OperationStats, Totals, TreeStats};
+use rbt_core::{Operation, RBTree};
+
+#[test]
+fn empty_tree_has_no_shape() {
+    let stats = TreeStats::of(&RBTree::new());
+    assert_eq!(stats, TreeStats::default());
+    assert_eq!(stats.height_bound(), 0.0);
+}
+
+#[test]
+fn shape_of_a_small_tree() {
+    // 10 (black) over 5 and 20 (black) with 25 red under 20.
+    let stats = TreeStats::of(&tree(&[10, 5, 20, 25]));
+    assert_eq!(stats.nodes, 4);
+    assert_eq!(stats.height, 3);
+    assert_eq!(stats.black_height, 2);
+    assert_eq!(stats.red_nodes, 1);
+    assert!(f64::from(stats.height) <= stats.height_bound());
+}
+
+#[test]
+fn operation_counts_come_from_the_trace() {
+    let mut tree = tree(&[10, 20]);
+    let trace = tree.apply_traced(Operation::Insert(30));
+    let stats = OperationStats::new(&trace, &tree);
+    assert_eq!(stats.operation, Operation::Insert(30));
+    assert_eq!(stats.comparisons, 2);
+    assert_eq!(stats.rotations, 1);
+    assert_eq!(stats.recolorings, 2);
+    assert_eq!(stats.after.height, 2);
+
+    let search = tree.apply_traced(Operation::Search(30));
+    let totals = Totals::of(&[stats, OperationStats::new(&search, &tree)]);
+    assert_eq!(totals.operations, 2);
+    assert_eq!(totals.comparisons, 4);
+    assert_eq!(totals.rotations, 1);
+    assert_eq!(totals.recolorings, 2);
+}
+
+#[test]
+fn height_stays_within_the_bound() {
+    let mut tree = RBTree::new();
+    for key in 1..=200 {
+        tree.insert(key);
+        let stats = TreeStats::of(&tree);
+        assert!(f64::from(stats.height) <= stats.height_bound());
+    }
+}
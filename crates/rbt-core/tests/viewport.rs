@@ -0,0 +1,70 @@
+use rbt_core::{Bounds, RBTree, TreeLayout, ViewBox};
+
+#[test]
+fn zoom_keeps_anchor_in_place() {
+    let view = ViewBox::new(0.0, 0.0, 200.0, 100.0);
+    let zoomed = view.zoom(0.5, 50.0, 25.0);
+
+    assert_eq!(zoomed, ViewBox::new(25.0, 12.5, 100.0, 50.0));
+    assert_eq!(zoomed.zoom(2.0, 50.0, 25.0), view);
+}
+
+#[test]
+fn center_on_keeps_size() {
+    let view = ViewBox::new(0.0, 0.0, 200.0, 100.0).center_on(10.0, 20.0);
+    assert_eq!(view, ViewBox::new(-90.0, -30.0, 200.0, 100.0));
+}
+
+#[test]
+fn zoom_stops_at_minimum_width() {
+    let view = ViewBox::new(0.0, 0.0, 40.0, 40.0);
+    assert_eq!(
+        view.zoom(0.1, 0.0, 0.0).width,
+        rbt_core::viewport::MIN_WIDTH
+    );
+}
+
+#[test]
+fn lerp_reaches_both_ends() {
+    let from = ViewBox::new(0.0, 0.0, 100.0, 100.0);
+    let to = ViewBox::new(50.0, -50.0, 300.0, 200.0);
+
+    assert_eq!(from.lerp(to, 0.0), from);
+    assert_eq!(from.lerp(to, 1.0), to);
+    assert_eq!(from.lerp(to, 0.5), ViewBox::new(25.0, -25.0, 200.0, 150.0));
+}
+
+#[test]
+fn screen_points_map_through_letterboxing() {
+    // A square box in a 400x200 element is letterboxed horizontally.
+    let view = ViewBox::new(0.0, 0.0, 100.0, 100.0);
+    assert_eq!(view.units_per_pixel(400.0, 200.0), 0.5);
+    assert_eq!(view.to_layout(200.0, 100.0, 400.0, 200.0), (50.0, 50.0));
+    assert_eq!(view.to_layout(0.0, 0.0, 400.0, 200.0), (-50.0, 0.0));
+}
+
+#[test]
+fn fit_contains_the_whole_layout() {
+    let mut tree = RBTree::new();
+    for key in 0..64 {
+        tree.insert(key);
+    }
+    let bounds = TreeLayout::new(&tree).bounds().unwrap();
+    let view = ViewBox::fit(bounds);
+
+    assert!(view.x <= bounds.min_x && view.y <= bounds.min_y);
+    assert!(view.x + view.width >= bounds.max_x && view.y + view.height >= bounds.max_y);
+}
+
+#[test]
+fn fit_keeps_small_trees_readable() {
+    let bounds = Bounds {
+        min_x: 90.0,
+        min_y: 10.0,
+        max_x: 110.0,
+        max_y: 30.0,
+    };
+    let view = ViewBox::fit(bounds);
+    assert_eq!(view.center(), (100.0, 20.0));
+    assert_eq!(view.width, rbt_core::viewport::FIT_MIN_SIZE);
+}
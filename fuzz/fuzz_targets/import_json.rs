@@ -0,0 +1,24 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rbt_core::export::json;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(mut tree) = json::from_json(input) {
+        assert_eq!(tree.validate(), Ok(()));
+        let exported = json::to_json(&tree);
+        assert_eq!(
+            json::to_json(&json::from_json(&exported).unwrap()),
+            exported
+        );
+
+        for key in 0..8 {
+            tree.insert(key);
+            assert_eq!(tree.validate(), Ok(()));
+        }
+    }
+});
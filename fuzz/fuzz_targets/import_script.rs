@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rbt_core::export::script;
+use rbt_core::RBTree;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+
+    if let Ok(operations) = script::parse(&input) {
+        let mut tree = RBTree::new();
+        for operation in operations.into_iter().take(1024) {
+            tree.apply(operation);
+        }
+        assert_eq!(tree.validate(), Ok(()));
+    }
+});
@@ -0,0 +1,59 @@
+#![no_main]
+
+use std::collections::BTreeSet;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use rbt_core::{Operation, RBTree};
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Insert(i8),
+    Delete(i8),
+    Search(i8),
+    Traced(Box<Op>),
+}
+
+impl Op {
+    fn operation(&self) -> Operation {
+        match self {
+            Op::Insert(key) => Operation::Insert(*key as i32),
+            Op::Delete(key) => Operation::Delete(*key as i32),
+            Op::Search(key) => Operation::Search(*key as i32),
+            Op::Traced(op) => op.operation(),
+        }
+    }
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut tree = RBTree::new();
+    let mut oracle = BTreeSet::new();
+
+    for op in ops {
+        let operation = op.operation();
+        match op {
+            Op::Traced(_) => {
+                let trace = tree.apply_traced(operation);
+                if let Some(last) = trace.steps.last() {
+                    assert_eq!(last.tree.validate(), Ok(()));
+                }
+            }
+            _ => tree.apply(operation),
+        }
+
+        match operation {
+            Operation::Insert(key) => {
+                oracle.insert(key);
+            }
+            Operation::Delete(key) => {
+                oracle.remove(&key);
+            }
+            Operation::Search(key) => {
+                assert_eq!(!tree.search(key).is_null(), oracle.contains(&key))
+            }
+        }
+
+        assert_eq!(tree.validate(), Ok(()));
+        assert_eq!(tree.len(), oracle.len());
+    }
+});
@@ -1,24 +1,124 @@
-use crate::algorithm::tree::{Color, Pointer, RBTree};
-use crate::components::canvas_control::CanvasControls;
-use crate::store::{RBTREE, SVG_VIEW_BOX};
+use crate::components::canvas_control::{
+    animate_view_box, fitted_view_box, set_view_box, CanvasControls,
+};
+use crate::components::inspector::Inspector;
+use crate::components::minimap::Minimap;
+use crate::components::traversal;
+use crate::history;
+use crate::store::{
+    AUTO_FIT, RBTREE, SCENE_OPTIONS, SEARCH_PATH, SELECTED_NODE, SVG_VIEW_BOX, TRAVERSAL,
+};
+use dioxus::html::geometry::{PixelsRect, WheelDelta};
 use dioxus::prelude::*;
+use rbt_core::layout::{TreeLayout, NODE_RADIUS};
+use rbt_core::scene::{fill, stroke, Scene, ANNOTATION_FILL, EDGE_STROKE, NIL_FILL, NIL_SIZE};
+use rbt_core::tree::{Pointer, RBTree};
+use std::rc::Rc;
 
 #[component]
 pub fn Canvas() -> Element {
-    let mut red_black_tree = use_signal(|| RBTree::new());
+    let mut red_black_tree = use_signal(RBTree::new);
+
+    let mut svg_element: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut svg_rect: Signal<PixelsRect> = use_signal(PixelsRect::zero);
+    let mut drag: Signal<Option<(f32, f32)>> = use_signal(|| None);
+    let mut pinch: Signal<Option<f32>> = use_signal(|| None);
 
     use_effect(move || {
         red_black_tree.set(RBTREE.read().clone());
+        SEARCH_PATH.write().clear();
+        traversal::cancel();
+    });
+
+    use_effect(move || {
+        let options = *SCENE_OPTIONS.read();
+        if *AUTO_FIT.read() {
+            if let Some(view_box) = fitted_view_box(&RBTREE.read(), options) {
+                animate_view_box(view_box);
+            }
+        }
     });
 
+    let measure = move || {
+        spawn(async move {
+            let element = svg_element.read().clone();
+            if let Some(element) = element {
+                if let Ok(rect) = element.get_client_rect().await {
+                    svg_rect.set(rect);
+                }
+            }
+        });
+    };
+
     rsx! {
-        div { class: "flex relative flex-col items-center justify-center w-full max-sm:min-h-[75%] rounded-xl bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg p-4 border border-slate-200",
+        div { class: "flex relative flex-col items-center justify-center w-full flex-1 min-h-0 rounded-xl bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg p-4 border border-slate-200",
             CanvasControls {}
+            Minimap {}
+            Inspector {}
             svg {
-                class: "overflow-scroll p-4",
+                class: "overflow-scroll touch-none cursor-grab active:cursor-grabbing",
                 width: "100%",
                 height: "100%",
-                view_box: SVG_VIEW_BOX.read().iter().map(|v| v.to_string()).collect::<Vec<String>>().join(" "),
+                view_box: "{SVG_VIEW_BOX}",
+                onmounted: move |event| {
+                    svg_element.set(Some(event.data()));
+                    measure();
+                },
+                onresize: move |_| measure(),
+                onpointerdown: move |event| {
+                    let point = event.client_coordinates();
+                    drag.set(Some((point.x as f32, point.y as f32)));
+                },
+                onpointermove: move |event| {
+                    let Some((last_x, last_y)) = *drag.read() else {
+                        return;
+                    };
+                    if pinch.read().is_some() {
+                        return;
+                    }
+                    let point = event.client_coordinates();
+                    let (x, y) = (point.x as f32, point.y as f32);
+                    let rect = *svg_rect.read();
+                    let view_box = *SVG_VIEW_BOX.peek();
+                    let scale = view_box.units_per_pixel(rect.width() as f32, rect.height() as f32);
+                    set_view_box(view_box.pan((last_x - x) * scale, (last_y - y) * scale));
+                    drag.set(Some((x, y)));
+                },
+                onpointerup: move |_| drag.set(None),
+                onpointerleave: move |_| drag.set(None),
+                onpointercancel: move |_| drag.set(None),
+                onwheel: move |event| {
+                    event.prevent_default();
+                    let rect = *svg_rect.read();
+                    let delta = match event.delta() {
+                        WheelDelta::Pixels(delta) => delta.y,
+                        WheelDelta::Lines(delta) => delta.y * LINE_HEIGHT,
+                        WheelDelta::Pages(delta) => delta.y * rect.height(),
+                    };
+                    let point = event.client_coordinates();
+                    zoom_at(&rect, (delta as f32 * WHEEL_ZOOM).exp(), point.x, point.y);
+                },
+                ontouchmove: move |event| {
+                    let touches = event.touches();
+                    if touches.len() != 2 {
+                        return;
+                    }
+                    event.prevent_default();
+                    drag.set(None);
+                    let a = touches[0].client_coordinates();
+                    let b = touches[1].client_coordinates();
+                    let distance = (a - b).length() as f32;
+                    if let Some(previous) = *pinch.read() {
+                        let rect = *svg_rect.read();
+                        zoom_at(&rect, previous / distance, (a.x + b.x) / 2.0, (a.y + b.y) / 2.0);
+                    }
+                    pinch.set(Some(distance));
+                },
+                ontouchend: move |event| {
+                    if event.touches().len() < 2 {
+                        pinch.set(None);
+                    }
+                },
 
                 defs {
                     marker {
@@ -37,7 +137,11 @@ pub fn Canvas() -> Element {
                 {
                     let tree = red_black_tree.read();
                     if !tree.root.is_null() {
-                        render_tree(&tree, tree.root, 100.0, 20.0)
+                        let roles = history::current().map(|state| state.highlight.roles).unwrap_or_default();
+                        render_tree(
+                            &Scene::with_options(&tree, &TreeLayout::new(&tree), *SCENE_OPTIONS.read()),
+                            &roles,
+                        )
                     } else {
                         rsx! {
                             g {}
@@ -49,83 +153,153 @@ pub fn Canvas() -> Element {
     }
 }
 
-fn render_tree(tree: &RBTree, pointer: Pointer, x: f32, y: f32) -> Element {
-    if pointer.is_null() {
-        return rsx! {
-            g {}
-        };
-    }
+const WHEEL_ZOOM: f32 = 0.0015;
+const SELECTED_STROKE: &str = "#f59e0b";
+const SEARCH_STROKE: &str = "#2563eb";
+const TRAVERSAL_STROKE: &str = "#0d9488";
+const ROLE_FILL: &str = "#7c3aed";
+const LINE_HEIGHT: f64 = 16.0;
 
-    let node = &tree[pointer];
-    let v_gap = 30.0;
-    let h_gap = 4.0 * (node.size as f32);
+// Zooms around the screen point `(client_x, client_y)` so it stays under the cursor.
+fn zoom_at(rect: &PixelsRect, factor: f32, client_x: f64, client_y: f64) {
+    let view_box = *SVG_VIEW_BOX.peek();
+    let (cx, cy) = view_box.to_layout(
+        (client_x - rect.origin.x) as f32,
+        (client_y - rect.origin.y) as f32,
+        rect.width() as f32,
+        rect.height() as f32,
+    );
+    set_view_box(view_box.zoom(factor, cx, cy));
+}
 
+fn role_label(roles: &[(&str, Pointer)], pointer: Pointer) -> Option<String> {
+    let names: Vec<&str> = roles
+        .iter()
+        .filter(|(_, node)| *node == pointer)
+        .map(|(name, _)| *name)
+        .collect();
+    (!names.is_empty()).then(|| names.join(", "))
+}
+
+fn render_tree(scene: &Scene, roles: &[(&str, Pointer)]) -> Element {
     rsx! {
         g {
-            circle {
-                cx: "{x}",
-                cy: "{y}",
-                r: "10",
-                stroke: if node.color == Color::Red { "#991b1b" } else { "#1f2937" },
-                stroke_width: "1.5",
-                fill: if node.color == Color::Red { "#ef4444" } else { "#475569" },
-                class: "transition-all duration-300 ease-in-out cursor-pointer hover:filter hover:brightness-110 hover:shadow-lg",
-            }
-            text {
-                x: "{x}",
-                y: "{y + 2.5}",
-                text_anchor: "middle",
-                fill: "white",
-                font_size: "6",
-                font_weight: "bold",
-                class: "transition-all duration-300 ease-in-out select-none pointer-events-none",
-                "{node.value}"
+            for edge in scene.edges.iter() {
+                line {
+                    x1: "{edge.x1}",
+                    y1: "{edge.y1}",
+                    x2: "{edge.x2}",
+                    y2: "{edge.y2}",
+                    stroke: EDGE_STROKE,
+                    stroke_width: "0.8",
+                    class: "transition-all duration-300 ease-in-out",
+                    marker_end: "url(#arrowhead)",
+                }
             }
-
-            {
-                if !node.left.is_null() {
-                    rsx! {
-                        g {
-                            line {
-                                x1: "{x - 2.5}",
-                                y1: "{y + 8.5}",
-                                x2: "{x - h_gap}",
-                                y2: "{y + v_gap}",
-                                stroke: "#475569",
-                                stroke_width: "0.8",
-                                class: "transition-all duration-300 ease-in-out",
-                                marker_end: "url(#arrowhead)",
-                            }
-                            {render_tree(tree, node.left, x - h_gap, y + v_gap)}
-                        }
+            for nil in scene.nils.iter() {
+                g {
+                    line {
+                        x1: "{nil.edge.x1}",
+                        y1: "{nil.edge.y1}",
+                        x2: "{nil.edge.x2}",
+                        y2: "{nil.edge.y2}",
+                        stroke: EDGE_STROKE,
+                        stroke_width: "0.5",
                     }
-                } else {
-                    rsx! {
-                        g {}
+                    rect {
+                        x: "{nil.x - NIL_SIZE / 2.0}",
+                        y: "{nil.y - NIL_SIZE / 2.0}",
+                        width: "{NIL_SIZE}",
+                        height: "{NIL_SIZE}",
+                        fill: NIL_FILL,
                     }
                 }
             }
-
-            {
-                if !node.right.is_null() {
-                    rsx! {
-                        g {
-                            line {
-                                x1: "{x + 2.5}",
-                                y1: "{y + 8.5}",
-                                x2: "{x + h_gap}",
-                                y2: "{y + v_gap}",
-                                stroke: "#475569",
-                                stroke_width: "0.8",
-                                class: "transition-all duration-300 ease-in-out",
-                                marker_end: "url(#arrowhead)",
-                            }
-                            {render_tree(tree, node.right, x + h_gap, y + v_gap)}
+            for node in scene.nodes.iter() {
+                g { key: "{node.pointer:?}",
+                    circle {
+                        cx: "{node.x}",
+                        cy: "{node.y}",
+                        r: "{NODE_RADIUS}",
+                        stroke: if *SELECTED_NODE.read() == Some(node.pointer) { SELECTED_STROKE } else if SEARCH_PATH.read().contains(&node.pointer) { SEARCH_STROKE } else if TRAVERSAL.read().contains(&node.pointer) { TRAVERSAL_STROKE } else { stroke(node.color) },
+                        stroke_width: if *SELECTED_NODE.read() == Some(node.pointer) || SEARCH_PATH.read().contains(&node.pointer) || TRAVERSAL.read().contains(&node.pointer) { "2.5" } else { "1.5" },
+                        fill: fill(node.color),
+                        class: "transition-all duration-300 ease-in-out cursor-pointer hover:filter hover:brightness-110 hover:shadow-lg",
+                        onclick: {
+                            let pointer = node.pointer;
+                            move |_| *SELECTED_NODE.write() = Some(pointer)
+                        },
+                    }
+                    text {
+                        x: "{node.x}",
+                        y: "{node.y + 2.5}",
+                        text_anchor: "middle",
+                        fill: "white",
+                        font_size: "6",
+                        font_weight: "bold",
+                        class: "transition-all duration-300 ease-in-out select-none pointer-events-none",
+                        "{node.label}"
+                    }
+                    if node.count > 1 {
+                        text {
+                            x: "{node.x + NODE_RADIUS - 2.0}",
+                            y: "{node.y + NODE_RADIUS + 2.0}",
+                            fill: ANNOTATION_FILL,
+                            font_size: "5",
+                            font_weight: "bold",
+                            class: "select-none pointer-events-none",
+                            "×{node.count}"
                         }
                     }
-                } else {
-                    rsx! {
-                        g {}
+                    if let Some(step) = TRAVERSAL.read().iter().position(|&pointer| pointer == node.pointer) {
+                        circle {
+                            cx: "{node.x - NODE_RADIUS + 1.0}",
+                            cy: "{node.y + NODE_RADIUS - 1.0}",
+                            r: "3.5",
+                            fill: TRAVERSAL_STROKE,
+                            class: "pointer-events-none",
+                        }
+                        text {
+                            x: "{node.x - NODE_RADIUS + 1.0}",
+                            y: "{node.y + NODE_RADIUS + 0.5}",
+                            text_anchor: "middle",
+                            fill: "white",
+                            font_size: "4",
+                            font_weight: "bold",
+                            class: "select-none pointer-events-none",
+                            "{step + 1}"
+                        }
+                    }
+                    if let Some(role) = role_label(roles, node.pointer) {
+                        circle {
+                            cx: "{node.x}",
+                            cy: "{node.y}",
+                            r: "{NODE_RADIUS + 2.5}",
+                            fill: "none",
+                            stroke: ROLE_FILL,
+                            stroke_width: "0.8",
+                            class: "pointer-events-none",
+                        }
+                        text {
+                            x: "{node.x - NODE_RADIUS - 2.0}",
+                            y: "{node.y - NODE_RADIUS + 2.0}",
+                            text_anchor: "end",
+                            fill: ROLE_FILL,
+                            font_size: "5",
+                            font_style: "italic",
+                            class: "select-none pointer-events-none",
+                            "{role}"
+                        }
+                    }
+                    if let Some(annotation) = &node.annotation {
+                        text {
+                            x: "{node.x + NODE_RADIUS + 1.0}",
+                            y: "{node.y - NODE_RADIUS + 4.0}",
+                            fill: ANNOTATION_FILL,
+                            font_size: "4",
+                            class: "select-none pointer-events-none",
+                            "{annotation}"
+                        }
                     }
                 }
             }
@@ -1,6 +1,57 @@
 use crate::components::ui::button::Button;
-use crate::store::{SELECTED_TREE, STATUS, SVG_VIEW_BOX};
+use crate::store::{AUTO_FIT, RBTREE, SCENE_OPTIONS, SELECTED_TREE, STATUS, SVG_VIEW_BOX};
+use crate::timer::sleep;
 use dioxus::prelude::*;
+use rbt_core::layout::TreeLayout;
+use rbt_core::scene::{frame_bounds, Scene, SceneOptions};
+use rbt_core::tree::RBTree;
+use rbt_core::viewport::ViewBox;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+pub const ZOOM_STEP: f32 = 0.1;
+const FIT_FRAMES: i32 = 15;
+const FRAME_MS: i32 = 16;
+
+// Bumped whenever the view box is set, so a running animation knows it has been superseded.
+static VIEW_ANIMATION: AtomicU32 = AtomicU32::new(0);
+
+pub fn set_view_box(view_box: ViewBox) {
+    VIEW_ANIMATION.fetch_add(1, Ordering::Relaxed);
+    *SVG_VIEW_BOX.write() = view_box;
+}
+
+pub fn zoom_view_box(factor: f32) {
+    let view_box = *SVG_VIEW_BOX.peek();
+    let (cx, cy) = view_box.center();
+    set_view_box(view_box.zoom(factor, cx, cy));
+}
+
+pub fn animate_view_box(target: ViewBox) {
+    let animation = VIEW_ANIMATION.fetch_add(1, Ordering::Relaxed) + 1;
+    let from = *SVG_VIEW_BOX.peek();
+    spawn(async move {
+        for frame in 1..=FIT_FRAMES {
+            sleep(FRAME_MS).await;
+            if VIEW_ANIMATION.load(Ordering::Relaxed) != animation {
+                return;
+            }
+            let t = frame as f32 / FIT_FRAMES as f32;
+            *SVG_VIEW_BOX.write() = from.lerp(target, 1.0 - (1.0 - t).powi(3));
+        }
+    });
+}
+
+/// View box showing all of `tree` as drawn with `options`, NIL leaves and annotations included.
+pub fn fitted_view_box(tree: &RBTree, options: SceneOptions) -> Option<ViewBox> {
+    let layout = TreeLayout::new(tree);
+    frame_bounds(&Scene::with_options(tree, &layout, options)).map(ViewBox::fit)
+}
+
+pub fn fit_view_box() {
+    if let Some(view_box) = fitted_view_box(&RBTREE.peek(), *SCENE_OPTIONS.peek()) {
+        animate_view_box(view_box);
+    }
+}
 
 #[component]
 pub fn CanvasControls() -> Element {
@@ -19,41 +70,34 @@ pub fn CanvasControls() -> Element {
             }
         }
 
-        div { class: "absolute top-4 left-4 bg-white/80 backdrop-blur-sm rounded-lg shadow-md px-4 py-2",
+        div { class: "absolute top-4 left-4 max-w-[50%] bg-white/80 backdrop-blur-sm rounded-lg shadow-md px-4 py-2",
             h1 { class: "text-sm font-semibold flex items-center gap-2",
                 "Status: "
-                span { class: "px-2 py-1 bg-gray-100 rounded-md text-gray-700", "{state}" }
+                span { class: "px-2 py-1 bg-gray-100 rounded-md text-gray-700 truncate", title: "{state}", "{state}" }
             }
         }
         div { class: "absolute bottom-4 right-4 flex gap-2",
             Button {
                 value: "+",
                 color: "bg-blue-600",
-                onclick: move |_| {
-                    let mut svg_view_box = SVG_VIEW_BOX.read().clone();
-                    let zoom_factor = 0.1;
-                    let width_reduction = svg_view_box[2] * zoom_factor;
-                    let height_reduction = svg_view_box[3] * zoom_factor;
-                    svg_view_box[0] += width_reduction / 2.0;
-                    svg_view_box[1] += height_reduction / 2.0;
-                    svg_view_box[2] -= width_reduction;
-                    svg_view_box[3] -= height_reduction;
-                    *SVG_VIEW_BOX.write() = svg_view_box as Vec<f32>;
-                },
+                onclick: move |_| zoom_view_box(1.0 - ZOOM_STEP),
             }
             Button {
                 value: "-",
                 color: "bg-blue-600",
+                onclick: move |_| zoom_view_box(1.0 + ZOOM_STEP),
+            }
+            Button {
+                value: "Fit",
+                color: "bg-blue-600",
+                onclick: move |_| fit_view_box(),
+            }
+            Button {
+                value: if *AUTO_FIT.read() { "Lock view" } else { "Unlock view" },
+                color: if *AUTO_FIT.read() { "bg-blue-600" } else { "bg-amber-500" },
                 onclick: move |_| {
-                    let mut svg_view_box = SVG_VIEW_BOX.read().clone();
-                    let zoom_factor = 0.1;
-                    let width_increase = svg_view_box[2] * zoom_factor;
-                    let height_increase = svg_view_box[3] * zoom_factor;
-                    svg_view_box[0] -= width_increase / 2.0;
-                    svg_view_box[1] -= height_increase / 2.0;
-                    svg_view_box[2] += width_increase;
-                    svg_view_box[3] += height_increase;
-                    *SVG_VIEW_BOX.write() = svg_view_box as Vec<f32>;
+                    let auto_fit = !*AUTO_FIT.read();
+                    *AUTO_FIT.write() = auto_fit;
                 },
             }
         }
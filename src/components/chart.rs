@@ -0,0 +1,93 @@
+use dioxus::prelude::*;
+
+const WIDTH: f64 = 300.0;
+const HEIGHT: f64 = 100.0;
+
+/// One line of a [`LineChart`], one value per operation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    pub label: &'static str,
+    pub color: &'static str,
+    pub values: Vec<f64>,
+    pub dashed: bool,
+}
+
+// Horizontal position of point `i` out of `len`, leaving a lone point in the middle.
+fn x(i: usize, len: usize) -> f64 {
+    if len > 1 {
+        i as f64 * WIDTH / (len - 1) as f64
+    } else {
+        WIDTH / 2.0
+    }
+}
+
+fn points(values: &[f64], max: f64) -> String {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            format!(
+                "{:.2},{:.2}",
+                x(i, values.len()),
+                HEIGHT - value / max * HEIGHT
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Line chart of a few series over the operations of the session, scaled to the largest value.
+#[component]
+pub fn LineChart(title: String, series: Vec<Series>) -> Element {
+    let max = series
+        .iter()
+        .flat_map(|series| series.values.iter().copied())
+        .fold(1.0, f64::max);
+
+    rsx! {
+        div { class: "flex flex-col gap-2 bg-white rounded-xl p-4 border border-slate-200 shadow",
+            h3 { class: "text-sm font-semibold text-slate-600", "{title}" }
+            div { class: "flex gap-2",
+                div { class: "flex flex-col justify-between text-[10px] text-slate-400 text-right w-8",
+                    span { "{max:.0}" }
+                    span { "0" }
+                }
+                svg {
+                    class: "flex-1 h-32 overflow-visible",
+                    view_box: "0 0 {WIDTH} {HEIGHT}",
+                    preserve_aspect_ratio: "none",
+                    line {
+                        x1: "0",
+                        y1: "{HEIGHT}",
+                        x2: "{WIDTH}",
+                        y2: "{HEIGHT}",
+                        stroke: "#cbd5e1",
+                        vector_effect: "non-scaling-stroke",
+                    }
+                    for series in series.iter() {
+                        polyline {
+                            key: "{series.label}",
+                            points: points(&series.values, max),
+                            fill: "none",
+                            stroke: series.color,
+                            stroke_width: "2",
+                            stroke_dasharray: if series.dashed { "6 4" } else { "none" },
+                            vector_effect: "non-scaling-stroke",
+                        }
+                    }
+                }
+            }
+            div { class: "flex flex-wrap gap-3 text-xs text-slate-600",
+                for series in series.iter() {
+                    span { key: "{series.label}", class: "flex items-center gap-1",
+                        span {
+                            class: "inline-block w-3 h-0.5",
+                            style: "background-color: {series.color}",
+                        }
+                        "{series.label}"
+                    }
+                }
+            }
+        }
+    }
+}
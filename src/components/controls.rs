@@ -1,18 +1,144 @@
+use crate::components::export::Export;
+use crate::components::traversal::Traversal;
 use crate::components::ui::button::Button;
 use crate::components::ui::input::Input;
+use crate::history;
 use crate::store::CONTROLS;
+use crate::store::DUPLICATES;
+use crate::store::PLAYING;
 use crate::store::RBTREE;
 use crate::store::RED_BLACK_TREE;
+use crate::store::SCENE_OPTIONS;
 use crate::store::SELECTED_TREE;
 use crate::store::TREE_STATES;
 use dioxus::prelude::*;
+use rbt_core::trace::Operation;
+use rbt_core::tree::Duplicates;
+
+const MIN_KEY: i32 = -9999;
+const MAX_KEY: i32 = 9999;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Field {
+    Insert,
+    Delete,
+    Search,
+}
+
+// Parses `text` as a key for `field`, explaining why it can't be used if it doesn't fit.
+fn validate(text: &str, field: Field) -> Result<i32, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Enter a key".to_string());
+    }
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("\"{text}\" is not a whole number"));
+    }
+    let key = text
+        .parse::<i32>()
+        .ok()
+        .filter(|key| (MIN_KEY..=MAX_KEY).contains(key))
+        .ok_or_else(|| format!("Keys must be between {MIN_KEY} and {MAX_KEY}"))?;
+
+    let present = !RED_BLACK_TREE.read().search(key).is_null();
+    match field {
+        Field::Insert if present && *DUPLICATES.read() == Duplicates::Reject => {
+            Err(format!("{key} is already in the tree"))
+        }
+        Field::Delete if !present => Err(format!("{key} is not in the tree")),
+        _ => Ok(key),
+    }
+}
+
+// Validates the field's text, showing the error or clearing the field once the key is accepted.
+fn accept(
+    field: Field,
+    mut text: Signal<String>,
+    mut error: Signal<Option<String>>,
+) -> Option<i32> {
+    let result = validate(&text.read(), field);
+    match result {
+        Ok(key) => {
+            text.set(String::new());
+            error.set(None);
+            Some(key)
+        }
+        Err(message) => {
+            error.set(Some(message));
+            None
+        }
+    }
+}
+
+// Live feedback while typing; an empty field is not an error until it is submitted.
+fn revalidate(
+    field: Field,
+    value: String,
+    mut text: Signal<String>,
+    mut error: Signal<Option<String>>,
+) {
+    error.set(if value.trim().is_empty() {
+        None
+    } else {
+        validate(&value, field).err()
+    });
+    text.set(value);
+}
 
 #[component]
 pub fn Controls() -> Element {
-    let mut addNode: Signal<i32> = use_signal(|| 0);
-    let mut deleteNode: Signal<i32> = use_signal(|| 0);
+    let addNode: Signal<String> = use_signal(String::new);
+    let deleteNode: Signal<String> = use_signal(String::new);
+    let searchNode: Signal<String> = use_signal(String::new);
+    let mut addError: Signal<Option<String>> = use_signal(|| None);
+    let deleteError: Signal<Option<String>> = use_signal(|| None);
+    let searchError: Signal<Option<String>> = use_signal(|| None);
     let mut disabled: Signal<bool> = use_signal(|| false);
 
+    let mut insert = move || {
+        let selected_tree = SELECTED_TREE.read().clone();
+        match selected_tree.as_str() {
+            "Red Black Tree" => {
+                if let Some(key) = accept(Field::Insert, addNode, addError) {
+                    *disabled.write() = true;
+                    spawn(async move {
+                        history::record(Operation::Insert(key));
+                        *disabled.write() = false;
+                    });
+                }
+            }
+            "Binomial Heap" => {}
+            _ => {}
+        }
+    };
+
+    let delete = move || {
+        let selected_tree = SELECTED_TREE.read().clone();
+        match selected_tree.as_str() {
+            "Red Black Tree" => {
+                if let Some(key) = accept(Field::Delete, deleteNode, deleteError) {
+                    history::record(Operation::Delete(key));
+                }
+            }
+            "Binomial Heap" => {}
+            _ => {}
+        }
+    };
+
+    let search = move || {
+        let selected_tree = SELECTED_TREE.read().clone();
+        match selected_tree.as_str() {
+            "Red Black Tree" => {
+                if let Some(key) = accept(Field::Search, searchNode, searchError) {
+                    history::record(Operation::Search(key));
+                }
+            }
+            "Binomial Heap" => {}
+            _ => {}
+        }
+    };
+
     rsx! {
       div { class: "flex flex-col gap-6 bg-gradient-to-br from-slate-50 to-slate-100 max-sm:overflow-scroll max-sm:w-full shadow-lg rounded-xl p-6 w-1/4 relative border border-slate-200",
 
@@ -44,9 +170,7 @@ pub fn Controls() -> Element {
                       _ => {}
                   }
                   *SELECTED_TREE.write() = e.value();
-                  CONTROLS.write().ind.set(-1);
-                  TREE_STATES.write().clear();
-                  RBTREE.write().clear_tree();
+                  history::clear();
               },
               option {
                 value: "",
@@ -64,14 +188,41 @@ pub fn Controls() -> Element {
           }
         }
 
+        div { class: "flex flex-col gap-3",
+          label { class: "text-sm font-medium text-slate-600", "Duplicate Keys" }
+          select {
+            class: "w-full px-4 py-2.5 bg-white border border-slate-300 rounded-lg shadow-sm cursor-pointer
+             hover:border-blue-400 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent
+             text-slate-700 font-medium",
+            title: "Changing the policy clears the tree",
+            onchange: move |e| {
+                *DUPLICATES.write() = match e.value().as_str() {
+                    "count" => Duplicates::Count,
+                    "multiset" => Duplicates::Multiset,
+                    _ => Duplicates::Reject,
+                };
+                history::clear();
+                addError.set(None);
+            },
+            option { value: "reject", selected: *DUPLICATES.read() == Duplicates::Reject, "Reject duplicates" }
+            option { value: "count", selected: *DUPLICATES.read() == Duplicates::Count, "Count copies on one node" }
+            option { value: "multiset", selected: *DUPLICATES.read() == Duplicates::Multiset, "Multiset (one node per copy)" }
+          }
+        }
+
         div { class: "flex flex-col gap-3",
           label { class: "text-sm font-medium text-slate-600", "Insert Node" }
           div { class: "flex gap-2",
             Input {
-              value: *addNode.read(),
+              id: "insert-input",
+              value: addNode.read().clone(),
+              error: addError.read().clone(),
               placeholder: "Enter a value to insert...",
-              oninput: move |value| {
-                  addNode.set(value);
+              oninput: move |value| revalidate(Field::Insert, value, addNode, addError),
+              onenter: move |_| {
+                  if history::is_at_latest() && !*disabled.read() {
+                      insert();
+                  }
               },
             }
             Button {
@@ -83,23 +234,7 @@ pub fn Controls() -> Element {
                       "bg-emerald-500 hover:bg-emerald-600 active:bg-emerald-700".to_string()
                   },
               ),
-              onclick: move |_| {
-                  let selected_tree = SELECTED_TREE.read().clone();
-                  let node_val = *addNode.read();
-                  match selected_tree.as_str() {
-                      "Red Black Tree" => {
-                          *disabled.write() = true;
-                          spawn(async move {
-                              RED_BLACK_TREE.write().insert(node_val.try_into().unwrap());
-                              *RBTREE.write() = RED_BLACK_TREE.read().clone();
-                              *disabled.write() = false;
-                          });
-                      }
-                      "Binomial Heap" => {}
-                      _ => {}
-                  }
-                  addNode.set(0);
-              },
+              onclick: move |_| insert(),
               disabled: *CONTROLS.read().ind.read() != TREE_STATES.read().len() as i32 - 1
                   || *disabled.read(),
             }
@@ -110,10 +245,15 @@ pub fn Controls() -> Element {
           label { class: "text-sm font-medium text-slate-600", "Delete Node" }
           div { class: "flex gap-2",
             Input {
-              value: *deleteNode.read(),
+              id: "delete-input",
+              value: deleteNode.read().clone(),
+              error: deleteError.read().clone(),
               placeholder: "Enter a value to delete...",
-              oninput: move |value| {
-                  deleteNode.set(value);
+              oninput: move |value| revalidate(Field::Delete, value, deleteNode, deleteError),
+              onenter: move |_| {
+                  if history::is_at_latest() {
+                      delete();
+                  }
               },
             }
             Button {
@@ -125,20 +265,97 @@ pub fn Controls() -> Element {
                   },
               ),
               value: "Delete",
-              onclick: move |_| {
-                  let selected_tree = SELECTED_TREE.read().clone();
-                  match selected_tree.as_str() {
-                      "Red Black Tree" => {}
-                      "Binomial Heap" => {}
-                      _ => {}
+              onclick: move |_| delete(),
+              disabled: *CONTROLS.read().ind.read() != TREE_STATES.read().len() as i32 - 1,
+            }
+          }
+        }
+
+        div { class: "flex flex-col gap-3",
+          label { class: "text-sm font-medium text-slate-600", "Search Node" }
+          div { class: "flex gap-2",
+            Input {
+              id: "search-input",
+              value: searchNode.read().clone(),
+              error: searchError.read().clone(),
+              placeholder: "Enter a value to search...",
+              oninput: move |value| revalidate(Field::Search, value, searchNode, searchError),
+              onenter: move |_| {
+                  if history::is_at_latest() {
+                      search();
                   }
-                  deleteNode.set(0);
               },
+            }
+            Button {
+              color: Some(
+                  if *CONTROLS.read().ind.read() != TREE_STATES.read().len() as i32 - 1 {
+                      "bg-gray-400 cursor-not-allowed".to_string()
+                  } else {
+                      "bg-blue-500 hover:bg-blue-600 active:bg-blue-700".to_string()
+                  },
+              ),
+              value: "Search",
+              onclick: move |_| search(),
               disabled: *CONTROLS.read().ind.read() != TREE_STATES.read().len() as i32 - 1,
             }
           }
         }
 
+        div { class: "flex flex-col gap-2",
+          label { class: "text-sm font-medium text-slate-600", "Display" }
+          label { class: "flex items-center gap-2 text-sm text-slate-600 cursor-pointer",
+            input {
+              r#type: "checkbox",
+              class: "accent-blue-500",
+              checked: SCENE_OPTIONS.read().nil_leaves,
+              onchange: move |e| {
+                  let show = e.checked();
+                  let mut options = SCENE_OPTIONS.write();
+                  options.nil_leaves = show;
+                  options.black_height = show;
+              },
+            }
+            "NIL leaves and black heights"
+          }
+          label { class: "flex items-center gap-2 text-sm text-slate-600 cursor-pointer",
+            input {
+              r#type: "checkbox",
+              class: "accent-blue-500",
+              checked: SCENE_OPTIONS.read().size,
+              onchange: move |e| {
+                  SCENE_OPTIONS.write().size = e.checked();
+              },
+            }
+            "Subtree sizes"
+          }
+          label { class: "flex items-center gap-2 text-sm text-slate-600 cursor-pointer",
+            input {
+              r#type: "checkbox",
+              class: "accent-blue-500",
+              checked: SCENE_OPTIONS.read().sum,
+              onchange: move |e| {
+                  SCENE_OPTIONS.write().sum = e.checked();
+              },
+            }
+            "Subtree sums"
+          }
+          label { class: "flex items-center gap-2 text-sm text-slate-600 cursor-pointer",
+            input {
+              r#type: "checkbox",
+              class: "accent-blue-500",
+              checked: SCENE_OPTIONS.read().min_max,
+              onchange: move |e| {
+                  SCENE_OPTIONS.write().min_max = e.checked();
+              },
+            }
+            "Subtree min / max"
+          }
+        }
+
+        Traversal {}
+
+        Export {}
+
         div { class: "flex mt-auto flex-col gap-3 pt-6 border-t border-slate-300",
           label { class: "text-sm font-medium text-slate-600 flex justify-between items-center",
             span { class: "flex justify-center items-center gap-2",
@@ -146,7 +363,7 @@ pub fn Controls() -> Element {
               "Animation Speed"
             }
             span { class: "px-2 py-1 bg-blue-100 text-blue-600 rounded-md text-xs font-semibold",
-              "{CONTROLS.read().speed} ms"
+              "{history::step_delay()} ms"
             }
           }
           div { class: "relative",
@@ -186,14 +403,11 @@ pub fn Controls() -> Element {
                       let selected_tree = SELECTED_TREE.read().clone();
                       match selected_tree.as_str() {
                           "Red Black Tree" => {
-                              *RBTREE.write() = TREE_STATES
-                                  .read()[(curr_ind - 1) as usize]
-                                  .clone();
+                              history::go_to(curr_ind - 1);
                           }
                           "Binomial Heap" => {}
                           _ => {}
                       }
-                      CONTROLS.write().ind.set(curr_ind - 1);
                   }
               },
               disabled: false,
@@ -202,9 +416,7 @@ pub fn Controls() -> Element {
               value: "Clear",
               color: Some("bg-slate-500 hover:bg-slate-600 active:bg-slate-700 w-full".to_string()),
               onclick: move |_| {
-                  RBTREE.write().clear_tree();
-                  TREE_STATES.write().clear();
-                  CONTROLS.write().ind.set(-1);
+                  history::clear();
               },
               disabled: *CONTROLS.read().ind.read() == -1,
             }
@@ -223,19 +435,22 @@ pub fn Controls() -> Element {
                       let selected_tree = SELECTED_TREE.read().clone();
                       match selected_tree.as_str() {
                           "Red Black Tree" => {
-                              *RBTREE.write() = TREE_STATES
-                                  .read()[(curr_ind + 1) as usize]
-                                  .clone();
+                              history::go_to(curr_ind + 1);
                           }
                           "Binomial Heap" => {}
                           _ => {}
                       }
-                      CONTROLS.write().ind.set(curr_ind + 1);
                   }
               },
               disabled: *CONTROLS.read().ind.read() == TREE_STATES.read().len() as i32 - 1,
             }
           }
+          Button {
+            value: if *PLAYING.read() { "⏸ Pause" } else { "▶ Play" },
+            color: Some("bg-indigo-500 hover:bg-indigo-600 active:bg-indigo-700 w-full".to_string()),
+            onclick: move |_| history::toggle_playback(),
+            disabled: TREE_STATES.read().is_empty(),
+          }
         }
       }
     }
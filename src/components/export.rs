@@ -0,0 +1,211 @@
+use crate::components::ui::button::Button;
+use crate::history;
+use crate::store::{RBTREE, SCENE_OPTIONS, TREE_STATES};
+use crate::timer::sleep;
+use dioxus::prelude::*;
+use rbt_core::export::animation::{self, AnimationFormat, AnimationOptions};
+use rbt_core::export::tikz::{self, Package};
+use rbt_core::export::{dot, svg};
+
+// Receives `[file name, MIME type, contents]` and hands it to the browser as a download.
+// Binary contents arrive as an array of bytes.
+const DOWNLOAD: &str = r#"
+const [name, type, contents] = await dioxus.recv();
+const data = Array.isArray(contents) ? new Uint8Array(contents) : contents;
+const url = URL.createObjectURL(new Blob([data], { type }));
+const link = document.createElement("a");
+link.href = url;
+link.download = name;
+link.click();
+URL.revokeObjectURL(url);
+"#;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Format {
+    Svg,
+    Dot,
+    Forest,
+    Qtree,
+    Animation(AnimationFormat),
+}
+
+impl Format {
+    fn parse(value: &str) -> Self {
+        match value {
+            "dot" => Format::Dot,
+            "forest" => Format::Forest,
+            "qtree" => Format::Qtree,
+            "gif" => Format::Animation(AnimationFormat::Gif),
+            "apng" => Format::Animation(AnimationFormat::Apng),
+            _ => Format::Svg,
+        }
+    }
+
+    fn file(self) -> (String, &'static str) {
+        match self {
+            Format::Svg => ("rb-tree.svg".to_string(), "image/svg+xml"),
+            Format::Dot => ("rb-tree.dot".to_string(), "text/vnd.graphviz"),
+            Format::Forest | Format::Qtree => ("rb-tree.tex".to_string(), "application/x-tex"),
+            Format::Animation(format) => (
+                format!("rb-tree.{}", format.extension()),
+                format.mime_type(),
+            ),
+        }
+    }
+
+    fn render(self) -> String {
+        let tree = RBTREE.read();
+        let options = *SCENE_OPTIONS.read();
+        match self {
+            Format::Svg | Format::Animation(_) => svg::to_svg_with(&tree, options),
+            Format::Dot => dot::to_dot_with(&tree, options),
+            Format::Forest => tikz::to_tikz(&tree, Package::Forest, options),
+            Format::Qtree => tikz::to_tikz(&tree, Package::Qtree, options),
+        }
+    }
+}
+
+fn download(format: Format) {
+    let (name, mime) = format.file();
+    let _ = document::eval(DOWNLOAD).send((name, mime, format.render()));
+}
+
+fn download_bytes(format: Format, bytes: Vec<u8>) {
+    let (name, mime) = format.file();
+    let _ = document::eval(DOWNLOAD).send((name, mime, bytes));
+}
+
+// Encodes the recorded steps `from..=to` (1-based, as shown in the timeline).
+fn animate(
+    format: AnimationFormat,
+    from: usize,
+    to: usize,
+    delay_ms: u32,
+) -> Result<Vec<u8>, String> {
+    let states = TREE_STATES.read();
+    let trees = states
+        .iter()
+        .skip(from.saturating_sub(1))
+        .take(to.saturating_sub(from) + 1)
+        .map(|state| &state.tree);
+    let options = AnimationOptions {
+        format,
+        delay_ms,
+        scene: *SCENE_OPTIONS.read(),
+        ..AnimationOptions::default()
+    };
+    animation::encode(trees, options).map_err(|err| err.to_string())
+}
+
+fn number(value: &str, default: usize) -> usize {
+    value.trim().parse().unwrap_or(default)
+}
+
+/// Downloads the tree on the canvas as SVG, Graphviz DOT or LaTeX, or the recorded steps as an
+/// animated GIF or APNG, honouring the display options.
+#[component]
+pub fn Export() -> Element {
+    let mut format = use_signal(|| Format::Svg);
+    let mut from = use_signal(|| "1".to_string());
+    let mut to = use_signal(String::new);
+    let mut delay = use_signal(|| history::step_delay().to_string());
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut encoding = use_signal(|| false);
+
+    let steps = TREE_STATES.read().len();
+    let animated = matches!(format(), Format::Animation(_));
+    let empty = if animated {
+        steps == 0
+    } else {
+        RBTREE.read().root.is_null()
+    };
+
+    let export = move |_| {
+        error.set(None);
+        let Format::Animation(animation) = format() else {
+            download(format());
+            return;
+        };
+        let last = TREE_STATES.read().len();
+        let start = number(&from.read(), 1).clamp(1, last);
+        let end = number(&to.read(), last).clamp(start, last);
+        let delay_ms = number(&delay.read(), history::step_delay() as usize) as u32;
+        encoding.set(true);
+        // Yield once so the button shows that encoding has started.
+        spawn(async move {
+            sleep(0).await;
+            match animate(animation, start, end, delay_ms) {
+                Ok(bytes) => download_bytes(format(), bytes),
+                Err(message) => error.set(Some(message)),
+            }
+            encoding.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-3",
+            label { class: "text-sm font-medium text-slate-600", "Export" }
+            div { class: "flex gap-2",
+                select {
+                    class: "flex-1 min-w-0 px-3 py-2 bg-white border border-slate-300 rounded-lg shadow-sm cursor-pointer
+                     hover:border-blue-400 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent
+                     text-slate-700 text-sm",
+                    onchange: move |e| format.set(Format::parse(&e.value())),
+                    option { value: "svg", "SVG image" }
+                    option { value: "dot", "Graphviz DOT" }
+                    option { value: "forest", "TikZ (forest)" }
+                    option { value: "qtree", "TikZ (tikz-qtree)" }
+                    option { value: "gif", "Animated GIF" }
+                    option { value: "apng", "Animated PNG" }
+                }
+                Button {
+                    value: if encoding() { "Encoding…" } else { "Download" },
+                    color: Some("bg-indigo-500 hover:bg-indigo-600 active:bg-indigo-700".to_string()),
+                    onclick: export,
+                    disabled: empty || encoding(),
+                }
+            }
+            if animated {
+                div { class: "grid grid-cols-3 gap-2 text-xs text-slate-500",
+                    label { class: "flex flex-col gap-1",
+                        "From step"
+                        input {
+                            class: "px-2 py-1 border border-slate-300 rounded-md text-slate-700",
+                            r#type: "number",
+                            min: "1",
+                            max: "{steps}",
+                            value: "{from}",
+                            oninput: move |e| from.set(e.value()),
+                        }
+                    }
+                    label { class: "flex flex-col gap-1",
+                        "To step"
+                        input {
+                            class: "px-2 py-1 border border-slate-300 rounded-md text-slate-700",
+                            r#type: "number",
+                            min: "1",
+                            max: "{steps}",
+                            placeholder: "{steps}",
+                            value: "{to}",
+                            oninput: move |e| to.set(e.value()),
+                        }
+                    }
+                    label { class: "flex flex-col gap-1",
+                        "Delay (ms)"
+                        input {
+                            class: "px-2 py-1 border border-slate-300 rounded-md text-slate-700",
+                            r#type: "number",
+                            min: "20",
+                            step: "10",
+                            value: "{delay}",
+                            oninput: move |e| delay.set(e.value()),
+                        }
+                    }
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "text-xs text-red-600", "{message}" }
+            }
+        }
+    }
+}
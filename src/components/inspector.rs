@@ -0,0 +1,133 @@
+use crate::components::ui::button::Button;
+use crate::components::ui::input::Input;
+use crate::history;
+use crate::store::{DUPLICATES, RBTREE, SEARCH_PATH, SELECTED_NODE, STATUS};
+use dioxus::prelude::*;
+use rbt_core::augment::{Max, Min, Sum};
+use rbt_core::trace::Operation;
+use rbt_core::tree::{Color, Duplicates, Pointer, RBTree};
+
+fn key_or_nil(tree: &RBTree, pointer: Pointer) -> String {
+    if pointer.is_null() {
+        "NIL".to_string()
+    } else {
+        tree[pointer].value.to_string()
+    }
+}
+
+// Highlights the search for `target` that starts at `node` instead of the root.
+fn search_from(node: Pointer, target: &str) -> Result<(), String> {
+    let target = target.trim();
+    let key: i32 = target
+        .parse()
+        .map_err(|_| format!("\"{target}\" is not a whole number"))?;
+    let tree = RBTREE.read();
+    let path = tree.search_path_from(node, key);
+    let found = path.last().is_some_and(|&last| tree[last].value == key);
+    *STATUS.write() = if found {
+        format!("Found {key} below {}", tree[node].value)
+    } else {
+        format!("{key} is not in the subtree of {}", tree[node].value)
+    };
+    *SEARCH_PATH.write() = path;
+    Ok(())
+}
+
+#[component]
+pub fn Inspector() -> Element {
+    let mut target = use_signal(String::new);
+    let mut target_error: Signal<Option<String>> = use_signal(|| None);
+    let mut search = move |node: Pointer| {
+        let result = search_from(node, &target.read());
+        target_error.set(result.err());
+    };
+
+    let Some(node) = *SELECTED_NODE.read() else {
+        return rsx! {};
+    };
+    let tree = RBTREE.read();
+    let Some(key) = tree.get(node).map(|node| node.value) else {
+        return rsx! {};
+    };
+
+    let mut rows = vec![
+        ("Key", key.to_string()),
+        (
+            "Color",
+            if tree[node].color == Color::Red {
+                "Red"
+            } else {
+                "Black"
+            }
+            .to_string(),
+        ),
+        ("Parent", key_or_nil(&tree, tree[node].parent)),
+        ("Left child", key_or_nil(&tree, tree[node].left)),
+        ("Right child", key_or_nil(&tree, tree[node].right)),
+        ("Subtree size", tree[node].size.to_string()),
+        ("Subtree sum", tree.aggregate::<Sum>(node).to_string()),
+        (
+            "Subtree range",
+            format!(
+                "{} … {}",
+                tree.aggregate::<Min>(node),
+                tree.aggregate::<Max>(node)
+            ),
+        ),
+        ("Depth", tree.depth(node).to_string()),
+        ("Black height", tree.black_height(node).to_string()),
+        ("Rank", tree.rank(node).to_string()),
+        ("Predecessor", key_or_nil(&tree, tree.predecessor(node))),
+        ("Successor", key_or_nil(&tree, tree.successor(node))),
+    ];
+    if *DUPLICATES.read() != Duplicates::Reject {
+        rows.insert(1, ("Copies of key", tree.count(key).to_string()));
+    }
+    // Deletes go by key, which in a multiset may find another node holding the same key.
+    let shared = *DUPLICATES.read() == Duplicates::Multiset && tree.count(key) > 1;
+
+    rsx! {
+        div { class: "absolute top-20 right-4 w-56 bg-white/90 backdrop-blur-sm rounded-lg shadow-md border border-slate-200 p-4 flex flex-col gap-3",
+            div { class: "flex justify-between items-center",
+                h2 { class: "text-sm font-semibold text-slate-700", "Node Inspector" }
+                button {
+                    class: "text-slate-400 hover:text-slate-600",
+                    onclick: move |_| *SELECTED_NODE.write() = None,
+                    i { class: "fas fa-xmark" }
+                }
+            }
+            dl { class: "grid grid-cols-2 gap-x-3 gap-y-1 text-xs",
+                for (label, value) in rows {
+                    dt { class: "text-slate-500", "{label}" }
+                    dd { class: "font-medium text-slate-700 text-right", "{value}" }
+                }
+            }
+            Input {
+                id: "inspector-search-input",
+                value: target.read().clone(),
+                error: target_error.read().clone(),
+                placeholder: "Key to search for below {key}",
+                oninput: move |value| target.set(value),
+                onenter: move |_| search(node),
+            }
+            if shared {
+                p { class: "text-xs text-slate-500",
+                    "Several nodes hold {key}, and deleting by key removes whichever one the search reaches first, so this node cannot be deleted on its own."
+                }
+            }
+            div { class: "grid grid-cols-2 gap-2",
+                Button {
+                    value: "Delete",
+                    color: Some("bg-red-500 hover:bg-red-600 active:bg-red-700".to_string()),
+                    disabled: !history::is_at_latest() || shared,
+                    onclick: move |_| history::record(Operation::Delete(key)),
+                }
+                Button {
+                    value: "Search from here",
+                    color: Some("bg-blue-500 hover:bg-blue-600 active:bg-blue-700".to_string()),
+                    onclick: move |_| search(node),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::history;
+use crate::store::{CONTROLS, TREE_STATES};
+use dioxus::prelude::*;
+
+#[component]
+pub fn Log() -> Element {
+    let ind = *CONTROLS.read().ind.read();
+    let states = TREE_STATES.read();
+    let last = states.len() as i32 - 1;
+
+    rsx! {
+        div { class: "flex flex-col gap-3 bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg rounded-xl p-6 border border-slate-200 min-h-0 flex-1",
+            div { class: "flex justify-center items-center gap-2",
+                i { class: "fas fa-list-ol text-blue-500 text-xl" }
+                h2 { class: "text-xl font-semibold text-slate-700", "Log" }
+            }
+            if states.is_empty() {
+                p { class: "text-sm text-slate-500", "Every step of an operation is explained here." }
+            }
+            ol { class: "flex flex-col gap-1 overflow-y-auto text-xs",
+                for (i, state) in states.iter().enumerate() {
+                    if state.step == 0 {
+                        li { key: "{i}-heading", class: "pt-2 font-semibold text-blue-600", "{history::describe(state.operation)}" }
+                    }
+                    li {
+                        key: "{i}",
+                        class: if i as i32 == ind { "px-2 py-1 rounded cursor-pointer bg-amber-200 text-slate-900" } else { "px-2 py-1 rounded cursor-pointer text-slate-600 hover:bg-slate-200" },
+                        onmounted: move |event| {
+                            if i as i32 == last {
+                                spawn(async move {
+                                    let _ = event.data().scroll_to(ScrollBehavior::Smooth).await;
+                                });
+                            }
+                        },
+                        onclick: move |_| history::go_to(i as i32),
+                        "{state.narration}"
+                    }
+                }
+            }
+        }
+    }
+}
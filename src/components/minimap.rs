@@ -0,0 +1,115 @@
+use crate::components::canvas_control::set_view_box;
+use crate::store::{RBTREE, SVG_VIEW_BOX};
+use dioxus::html::geometry::PixelsRect;
+use dioxus::prelude::*;
+use rbt_core::layout::TreeLayout;
+use rbt_core::scene::{fill, frame_bounds, Scene, EDGE_STROKE};
+use rbt_core::viewport::ViewBox;
+use std::rc::Rc;
+
+#[component]
+pub fn Minimap() -> Element {
+    let mut element: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut rect: Signal<PixelsRect> = use_signal(PixelsRect::zero);
+    let mut dragging = use_signal(|| false);
+
+    let tree = RBTREE.read();
+    let layout = TreeLayout::new(&tree);
+    let scene = Scene::new(&tree, &layout);
+    let Some(bounds) = frame_bounds(&scene) else {
+        return rsx! {};
+    };
+    let overview = ViewBox::fit(bounds);
+    let view = *SVG_VIEW_BOX.read();
+
+    let measure = move || {
+        spawn(async move {
+            let mounted = element.read().clone();
+            if let Some(mounted) = mounted {
+                if let Ok(client_rect) = mounted.get_client_rect().await {
+                    rect.set(client_rect);
+                }
+            }
+        });
+    };
+
+    // Centres the main view on the overview point under the pointer.
+    let navigate = move |event: PointerEvent| {
+        let rect = *rect.read();
+        let point = event.client_coordinates();
+        let (x, y) = overview.to_layout(
+            (point.x - rect.origin.x) as f32,
+            (point.y - rect.origin.y) as f32,
+            rect.width() as f32,
+            rect.height() as f32,
+        );
+        set_view_box(SVG_VIEW_BOX.peek().center_on(x, y));
+    };
+
+    rsx! {
+        div { class: "absolute bottom-4 left-4 w-40 h-32 bg-white/80 backdrop-blur-sm rounded-lg shadow-md border border-slate-200 overflow-hidden",
+            svg {
+                class: "touch-none cursor-crosshair",
+                width: "100%",
+                height: "100%",
+                view_box: "{overview}",
+                onmounted: move |event| {
+                    element.set(Some(event.data()));
+                    measure();
+                },
+                onresize: move |_| measure(),
+                onpointerdown: move |event| {
+                    dragging.set(true);
+                    navigate(event);
+                },
+                onpointermove: move |event| {
+                    if *dragging.read() {
+                        navigate(event);
+                    }
+                },
+                onpointerup: move |_| dragging.set(false),
+                onpointerleave: move |_| dragging.set(false),
+
+                {render_miniature(&scene)}
+                rect {
+                    x: "{view.x}",
+                    y: "{view.y}",
+                    width: "{view.width}",
+                    height: "{view.height}",
+                    fill: "#3b82f6",
+                    fill_opacity: "0.1",
+                    stroke: "#2563eb",
+                    stroke_width: "{overview.width / 80.0}",
+                    class: "pointer-events-none",
+                }
+            }
+        }
+    }
+}
+
+/// Edges and plain coloured discs, for thumbnails too small to show keys.
+pub fn render_miniature(scene: &Scene) -> Element {
+    rsx! {
+        g {
+            for edge in scene.edges.iter() {
+                line {
+                    x1: "{edge.x1}",
+                    y1: "{edge.y1}",
+                    x2: "{edge.x2}",
+                    y2: "{edge.y2}",
+                    stroke: EDGE_STROKE,
+                    stroke_width: "2",
+                }
+            }
+            for node in scene.nodes.iter() {
+                circle {
+                    key: "{node.pointer:?}",
+                    cx: "{node.x}",
+                    cy: "{node.y}",
+                    r: "10",
+                    fill: fill(node.color),
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,15 @@
 pub mod canvas;
 pub mod canvas_control;
+pub mod chart;
 pub mod controls;
+pub mod export;
+pub mod inspector;
+pub mod log;
+pub mod minimap;
 pub mod navbar;
+pub mod pseudocode;
+pub mod shortcuts;
+pub mod timeline;
+pub mod traversal;
+pub mod tree_view;
 pub mod ui;
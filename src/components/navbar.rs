@@ -19,6 +19,31 @@ pub fn Navbar() -> Element {
             href: "/",
             "Home"
           }
+          a {
+            class: "text-white text-sm hover:bg-gray-700 hover:text-gray-200 p-2 rounded-md px-4 transition-all duration-200 font-medium",
+            href: "/learn",
+            "Learn"
+          }
+          a {
+            class: "text-white text-sm hover:bg-gray-700 hover:text-gray-200 p-2 rounded-md px-4 transition-all duration-200 font-medium",
+            href: "/quiz",
+            "Quiz"
+          }
+          a {
+            class: "text-white text-sm hover:bg-gray-700 hover:text-gray-200 p-2 rounded-md px-4 transition-all duration-200 font-medium",
+            href: "/sandbox",
+            "Sandbox"
+          }
+          a {
+            class: "text-white text-sm hover:bg-gray-700 hover:text-gray-200 p-2 rounded-md px-4 transition-all duration-200 font-medium",
+            href: "/intervals",
+            "Intervals"
+          }
+          a {
+            class: "text-white text-sm hover:bg-gray-700 hover:text-gray-200 p-2 rounded-md px-4 transition-all duration-200 font-medium",
+            href: "/stats",
+            "Stats"
+          }
           a {
             class: "text-white text-sm hover:bg-gray-700 hover:text-gray-200 p-2 rounded-md px-4 transition-all duration-200 font-medium",
             href: "/about",
@@ -0,0 +1,44 @@
+use crate::history;
+use dioxus::prelude::*;
+
+#[component]
+pub fn Pseudocode() -> Element {
+    let state = history::current();
+
+    rsx! {
+        div { class: "flex flex-col gap-3 bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg rounded-xl p-6 border border-slate-200 overflow-y-auto shrink-0 max-h-[60%]",
+            div { class: "flex justify-center items-center gap-2",
+                i { class: "fas fa-code text-blue-500 text-xl" }
+                h2 { class: "text-xl font-semibold text-slate-700", "Pseudocode" }
+            }
+            if let Some(state) = state {
+                {
+                    let operation = history::describe(state.operation);
+                    let highlight = state.highlight;
+                    let procedure = highlight.procedure;
+                    rsx! {
+                        p { class: "text-sm font-medium text-blue-600", "{operation}" }
+                        h3 { class: "font-mono text-sm font-semibold text-slate-700", "{procedure.name()}" }
+                        if let Some((caller, line)) = highlight.caller {
+                            p { class: "text-xs text-slate-500", "called from {caller.name()}, line {line}" }
+                        }
+                        if highlight.mirrored {
+                            p { class: "text-xs text-amber-600", "Running the branch with \"right\" and \"left\" exchanged" }
+                        }
+                        ol { class: "font-mono text-xs text-slate-600",
+                            for (i, text) in procedure.lines().iter().enumerate() {
+                                li {
+                                    class: if highlight.lines.contains(&(i + 1)) { "flex gap-2 px-1 rounded bg-amber-200 text-slate-900" } else { "flex gap-2 px-1" },
+                                    span { class: "w-5 shrink-0 text-right text-slate-400 select-none", "{i + 1}" }
+                                    span { class: "whitespace-pre", "{text}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                p { class: "text-sm text-slate-500", "Insert or delete a key to step through the algorithm." }
+            }
+        }
+    }
+}
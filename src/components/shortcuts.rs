@@ -0,0 +1,93 @@
+use crate::components::canvas_control::{fit_view_box, zoom_view_box, ZOOM_STEP};
+use crate::history;
+use crate::store::{CONTROLS, SHORTCUTS_OPEN};
+use dioxus::prelude::*;
+
+// Focus shortcuts are handled in the page so the key is not typed into the newly focused field.
+// Everything else is forwarded, except while the user is typing in a form field.
+const LISTENER: &str = r#"
+const focus = { i: "insert-input", d: "delete-input", s: "search-input" };
+document.addEventListener("keydown", (event) => {
+    if (event.ctrlKey || event.metaKey || event.altKey) return;
+    const tag = event.target.tagName;
+    if (tag === "INPUT" || tag === "SELECT" || tag === "TEXTAREA") {
+        if (event.key === "Escape") event.target.blur();
+        return;
+    }
+    if (focus[event.key]) {
+        event.preventDefault();
+        document.getElementById(focus[event.key])?.focus();
+        return;
+    }
+    if (event.key === " " || event.key.startsWith("Arrow")) event.preventDefault();
+    dioxus.send(event.key);
+});
+"#;
+
+const SHORTCUTS: &[(&str, &str)] = &[
+    ("← / →", "Previous / next step"),
+    ("Space", "Play / pause"),
+    ("i", "Focus insert"),
+    ("d", "Focus delete"),
+    ("s", "Focus search"),
+    ("Enter", "Submit the focused field"),
+    ("Esc", "Leave the field / close this sheet"),
+    ("+ / -", "Zoom in / out"),
+    ("0", "Fit the tree"),
+    ("?", "Show this sheet"),
+];
+
+fn handle(key: &str) {
+    let ind = *CONTROLS.read().ind.read();
+    match key {
+        "ArrowLeft" => history::go_to(ind - 1),
+        "ArrowRight" => history::go_to(ind + 1),
+        " " => history::toggle_playback(),
+        "+" | "=" => zoom_view_box(1.0 - ZOOM_STEP),
+        "-" => zoom_view_box(1.0 + ZOOM_STEP),
+        "0" => fit_view_box(),
+        "?" => {
+            let open = !*SHORTCUTS_OPEN.read();
+            *SHORTCUTS_OPEN.write() = open;
+        }
+        "Escape" => *SHORTCUTS_OPEN.write() = false,
+        _ => {}
+    }
+}
+
+/// Installs the global key bindings and renders the cheat sheet when it is open.
+#[component]
+pub fn Shortcuts() -> Element {
+    use_hook(|| {
+        spawn(async move {
+            let mut listener = document::eval(LISTENER);
+            while let Ok(key) = listener.recv::<String>().await {
+                handle(&key);
+            }
+        });
+    });
+
+    if !*SHORTCUTS_OPEN.read() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-[100] flex items-center justify-center bg-slate-900/40",
+            onclick: move |_| *SHORTCUTS_OPEN.write() = false,
+            div {
+                class: "bg-white rounded-xl shadow-xl border border-slate-200 p-6 w-80",
+                onclick: move |event| event.stop_propagation(),
+                h2 { class: "text-lg font-semibold text-slate-700 mb-4", "Keyboard Shortcuts" }
+                dl { class: "grid grid-cols-[auto_1fr] gap-x-4 gap-y-2 text-sm",
+                    for (keys, action) in SHORTCUTS {
+                        dt {
+                            kbd { class: "px-2 py-0.5 rounded border border-slate-300 bg-slate-100 font-mono text-xs", "{keys}" }
+                        }
+                        dd { class: "text-slate-600", "{action}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::components::minimap::render_miniature;
+use crate::history;
+use crate::store::{CONTROLS, TREE_STATES};
+use dioxus::html::geometry::PixelsRect;
+use dioxus::prelude::*;
+use rbt_core::layout::TreeLayout;
+use rbt_core::scene::{frame_bounds, Scene};
+use rbt_core::trace::Operation;
+use rbt_core::viewport::ViewBox;
+use std::rc::Rc;
+
+fn tick_color(operation: Operation) -> &'static str {
+    match operation {
+        Operation::Insert(_) => "bg-emerald-500",
+        Operation::Delete(_) => "bg-red-500",
+        Operation::Search(_) => "bg-blue-500",
+    }
+}
+
+// Percentage offset of history index `i` along a track holding `len` states.
+fn offset(i: usize, len: usize) -> f64 {
+    if len > 1 {
+        i as f64 * 100.0 / (len - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+#[component]
+pub fn Timeline() -> Element {
+    let mut element: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut rect: Signal<PixelsRect> = use_signal(PixelsRect::zero);
+    let mut hover: Signal<Option<usize>> = use_signal(|| None);
+    let mut scrubbing = use_signal(|| false);
+
+    let states = TREE_STATES.read();
+    let len = states.len();
+    let ind = *CONTROLS.read().ind.read();
+
+    let measure = move || {
+        spawn(async move {
+            let mounted = element.read().clone();
+            if let Some(mounted) = mounted {
+                if let Ok(client_rect) = mounted.get_client_rect().await {
+                    rect.set(client_rect);
+                }
+            }
+        });
+    };
+
+    // History index under the pointer, snapped to the nearest state.
+    let index_at = move |client_x: f64| {
+        let rect = *rect.read();
+        if len == 0 || rect.width() <= 0.0 {
+            return None;
+        }
+        let t = ((client_x - rect.origin.x) / rect.width()).clamp(0.0, 1.0);
+        Some((t * (len - 1) as f64).round() as usize)
+    };
+
+    let preview = hover.read().and_then(|i| {
+        let tree = &states.get(i)?.tree;
+        let layout = TreeLayout::new(tree);
+        let scene = Scene::new(tree, &layout);
+        let view = ViewBox::fit(frame_bounds(&scene)?);
+        Some((i, scene, view))
+    });
+
+    rsx! {
+        div { class: "flex items-center gap-3 bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg rounded-xl px-4 py-2 border border-slate-200",
+            span { class: "text-xs font-medium text-slate-500 w-16 shrink-0",
+                if len == 0 { "0 / 0" } else { "{ind + 1} / {len}" }
+            }
+            div {
+                class: "relative flex-1 h-8 cursor-pointer select-none",
+                onmounted: move |event| {
+                    element.set(Some(event.data()));
+                    measure();
+                },
+                onresize: move |_| measure(),
+                onmousedown: move |event| {
+                    scrubbing.set(true);
+                    if let Some(i) = index_at(event.client_coordinates().x) {
+                        history::go_to(i as i32);
+                    }
+                },
+                onmousemove: move |event| {
+                    let i = index_at(event.client_coordinates().x);
+                    hover.set(i);
+                    if let (true, Some(i)) = (*scrubbing.read(), i) {
+                        history::go_to(i as i32);
+                    }
+                },
+                onmouseup: move |_| scrubbing.set(false),
+                onmouseleave: move |_| {
+                    hover.set(None);
+                    scrubbing.set(false);
+                },
+                div { class: "absolute left-0 right-0 top-1/2 h-1 -translate-y-1/2 rounded bg-slate-200" }
+                if ind >= 0 {
+                    div {
+                        class: "absolute left-0 top-1/2 h-1 -translate-y-1/2 rounded bg-blue-300",
+                        style: "width: {offset(ind as usize, len)}%",
+                    }
+                }
+                for (i, state) in states.iter().enumerate().filter(|(_, state)| state.step == 0) {
+                    div {
+                        key: "{i}",
+                        class: "absolute top-1 h-6 w-0.5 -translate-x-1/2 rounded {tick_color(state.operation)}",
+                        style: "left: {offset(i, len)}%",
+                        title: "{history::describe(state.operation)}",
+                    }
+                }
+                if ind >= 0 {
+                    div {
+                        class: "absolute top-1/2 w-3 h-3 -translate-x-1/2 -translate-y-1/2 rounded-full bg-blue-600 shadow pointer-events-none",
+                        style: "left: {offset(ind as usize, len)}%",
+                    }
+                }
+                if let Some((i, scene, view)) = preview {
+                    div {
+                        class: "absolute bottom-10 w-32 -translate-x-1/2 bg-white/90 rounded-lg shadow-md border border-slate-200 p-1 pointer-events-none z-10",
+                        style: "left: {offset(i, len)}%",
+                        svg { width: "100%", height: "80", view_box: "{view}", {render_miniature(&scene)} }
+                        p { class: "text-center text-[10px] text-slate-500",
+                            "#{i + 1} · {history::describe(states[i].operation)}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::components::ui::button::Button;
+use crate::components::ui::input::Input;
+use crate::history;
+use crate::store::{RBTREE, STATUS, TRAVERSAL};
+use crate::timer::sleep;
+use dioxus::prelude::*;
+use rbt_core::tree::Pointer;
+use std::ops::Bound;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// Bumped whenever a walk starts or is cancelled, so a stale walk knows to exit.
+static WALK: AtomicU32 = AtomicU32::new(0);
+
+/// Stops any walk in progress and clears its marks from the canvas.
+pub fn cancel() {
+    WALK.fetch_add(1, Ordering::Relaxed);
+    TRAVERSAL.write().clear();
+}
+
+// An empty field leaves that end of the range open.
+fn bound(text: &str) -> Result<Bound<i32>, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(Bound::Unbounded);
+    }
+    text.parse()
+        .map(Bound::Included)
+        .map_err(|_| format!("\"{text}\" is not a whole number"))
+}
+
+// Lights up the nodes of the tree on the canvas one by one, in key order or in reverse.
+fn walk(range: (Bound<i32>, Bound<i32>), reverse: bool) {
+    cancel();
+    let walk = WALK.load(Ordering::Relaxed);
+    let tree = RBTREE.read().clone();
+    let nodes: Vec<(Pointer, i32)> = if reverse {
+        tree.range(range)
+            .rev()
+            .map(|(node, n)| (node, n.value))
+            .collect()
+    } else {
+        tree.range(range).map(|(node, n)| (node, n.value)).collect()
+    };
+    if nodes.is_empty() {
+        *STATUS.write() = "No keys in that range".to_string();
+        return;
+    }
+
+    spawn(async move {
+        let mut keys = Vec::new();
+        for (node, key) in nodes {
+            if WALK.load(Ordering::Relaxed) != walk {
+                return;
+            }
+            TRAVERSAL.write().push(node);
+            keys.push(key.to_string());
+            *STATUS.write() = format!(
+                "{}: {}",
+                if reverse { "Reverse order" } else { "In order" },
+                keys.join(", ")
+            );
+            sleep(history::step_delay()).await;
+        }
+    });
+}
+
+/// Walks the in-order traversal on the canvas, optionally limited to a range of keys.
+#[component]
+pub fn Traversal() -> Element {
+    let mut from = use_signal(String::new);
+    let mut from_error: Signal<Option<String>> = use_signal(|| None);
+    let mut to = use_signal(String::new);
+    let mut to_error: Signal<Option<String>> = use_signal(|| None);
+    let empty = RBTREE.read().is_empty();
+
+    let mut start = move |reverse: bool| {
+        let lo = bound(&from.read());
+        let hi = bound(&to.read());
+        from_error.set(lo.clone().err());
+        to_error.set(hi.clone().err());
+        if let (Ok(lo), Ok(hi)) = (lo, hi) {
+            walk((lo, hi), reverse);
+        }
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-3",
+            label { class: "text-sm font-medium text-slate-600", "In-order Walk" }
+            div { class: "grid grid-cols-2 gap-2",
+                Input {
+                    id: "walk-from-input",
+                    value: from.read().clone(),
+                    error: from_error.read().clone(),
+                    placeholder: "From (smallest)",
+                    oninput: move |value| from.set(value),
+                    onenter: move |_| start(false),
+                }
+                Input {
+                    id: "walk-to-input",
+                    value: to.read().clone(),
+                    error: to_error.read().clone(),
+                    placeholder: "To (largest)",
+                    oninput: move |value| to.set(value),
+                    onenter: move |_| start(false),
+                }
+            }
+            div { class: "grid grid-cols-3 gap-2",
+                Button {
+                    value: "⟸ Reverse",
+                    color: Some("bg-teal-500 hover:bg-teal-600 active:bg-teal-700".to_string()),
+                    disabled: empty,
+                    onclick: move |_| start(true),
+                }
+                Button {
+                    value: "Stop",
+                    color: Some("bg-slate-500 hover:bg-slate-600 active:bg-slate-700".to_string()),
+                    disabled: TRAVERSAL.read().is_empty(),
+                    onclick: move |_| cancel(),
+                }
+                Button {
+                    value: "Walk ⟹",
+                    color: Some("bg-teal-500 hover:bg-teal-600 active:bg-teal-700".to_string()),
+                    disabled: empty,
+                    onclick: move |_| start(false),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use dioxus::prelude::*;
+use rbt_core::layout::{TreeLayout, NODE_RADIUS};
+use rbt_core::scene::{
+    fill, frame_bounds, stroke, Scene, SceneOptions, ANNOTATION_FILL, EDGE_STROKE,
+};
+use rbt_core::tree::{Pointer, RBTree};
+use rbt_core::viewport::ViewBox;
+
+const SELECTED_STROKE: &str = "#f59e0b";
+const MARKED_STROKE: &str = "#dc2626";
+
+/// A self-contained, auto-fitted drawing of `tree` for pages that work on their own tree
+/// rather than the recorded history. Nodes in `marked` get a warning outline.
+#[component]
+pub fn TreeView(
+    tree: ReadOnlySignal<RBTree>,
+    #[props(default)] selected: Option<Pointer>,
+    #[props(default)] marked: Vec<Pointer>,
+    #[props(default)] options: SceneOptions,
+    onselect: Option<EventHandler<Pointer>>,
+) -> Element {
+    let tree = tree.read();
+    let layout = TreeLayout::new(&tree);
+    let scene = Scene::with_options(&tree, &layout, options);
+    let Some(bounds) = frame_bounds(&scene) else {
+        return rsx! {
+            div { class: "flex items-center justify-center w-full h-full text-sm text-slate-400",
+                "The tree is empty"
+            }
+        };
+    };
+    let view_box = ViewBox::fit(bounds);
+
+    rsx! {
+        svg { width: "100%", height: "100%", view_box: "{view_box}",
+            for edge in scene.edges.iter() {
+                line {
+                    x1: "{edge.x1}",
+                    y1: "{edge.y1}",
+                    x2: "{edge.x2}",
+                    y2: "{edge.y2}",
+                    stroke: EDGE_STROKE,
+                    stroke_width: "0.8",
+                }
+            }
+            for node in scene.nodes.iter() {
+                g { key: "{node.pointer:?}",
+                    circle {
+                        cx: "{node.x}",
+                        cy: "{node.y}",
+                        r: "{NODE_RADIUS}",
+                        stroke: if selected == Some(node.pointer) { SELECTED_STROKE } else if marked.contains(&node.pointer) { MARKED_STROKE } else { stroke(node.color) },
+                        stroke_width: if selected == Some(node.pointer) || marked.contains(&node.pointer) { "2.5" } else { "1.5" },
+                        fill: fill(node.color),
+                        class: if onselect.is_some() { "transition-all duration-300 ease-in-out cursor-pointer hover:brightness-110" } else { "transition-all duration-300 ease-in-out" },
+                        onclick: {
+                            let pointer = node.pointer;
+                            move |_| {
+                                if let Some(onselect) = onselect {
+                                    onselect.call(pointer);
+                                }
+                            }
+                        },
+                    }
+                    text {
+                        x: "{node.x}",
+                        y: "{node.y + 2.5}",
+                        text_anchor: "middle",
+                        fill: "white",
+                        font_size: "6",
+                        font_weight: "bold",
+                        class: "select-none pointer-events-none",
+                        "{node.label}"
+                    }
+                    if let Some(annotation) = &node.annotation {
+                        text {
+                            x: "{node.x + NODE_RADIUS + 1.0}",
+                            y: "{node.y - NODE_RADIUS + 4.0}",
+                            fill: ANNOTATION_FILL,
+                            font_size: "4",
+                            class: "select-none pointer-events-none",
+                            "{annotation}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
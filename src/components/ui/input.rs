@@ -3,33 +3,41 @@ use dioxus::prelude::*;
 #[derive(PartialEq, Props, Clone)]
 pub struct InputProps {
     placeholder: String,
-    value: i32,
-    oninput: EventHandler<i32>,
-    error: Option<bool>,
+    value: String,
+    oninput: EventHandler<String>,
+    error: Option<String>,
+    id: Option<String>,
+    onenter: Option<EventHandler<()>>,
 }
 
 pub fn Input(props: InputProps) -> Element {
-    let base_classes = "w-full p-2 rounded-lg transition-all duration-200 outline-none";
     let border_classes = match props.error {
-        Some(true) => "border-2 border-red-500 focus:border-red-600",
-        _ => "border-2 border-gray-300 focus:border-blue-500 hover:border-gray-400",
+        Some(_) => "border-red-500 focus:border-red-600",
+        None => "focus:border-blue-500 hover:border-gray-400",
     };
-    let _input_classes = format!(
-        "{} {} bg-white shadow-sm hover:shadow focus:shadow-md",
-        base_classes, border_classes
-    );
 
     rsx! {
-        input {
-            r#type: "text",
-            placeholder: "{props.placeholder}",
-            class: "w-full border-2 p-2 rounded-md outline-none transition-all duration-200 focus:border-blue-500 hover:border-gray-400 bg-white/50 backdrop-blur-sm",
-            value: if props.value != 0 { props.value.to_string() } else { "".to_string() },
-            oninput: move |event| {
-                if let Ok(value) = event.value().parse::<i32>() {
-                    props.oninput.call(value);
-                }
-            },
+        div { class: "flex flex-col gap-1 w-full",
+            input {
+                r#type: "text",
+                inputmode: "numeric",
+                id: props.id.clone(),
+                placeholder: "{props.placeholder}",
+                class: "w-full border-2 p-2 rounded-md outline-none transition-all duration-200 bg-white/50 backdrop-blur-sm {border_classes}",
+                aria_invalid: props.error.is_some(),
+                value: "{props.value}",
+                oninput: move |event| props.oninput.call(event.value()),
+                onkeydown: move |event| {
+                    if event.key() == Key::Enter {
+                        if let Some(onenter) = props.onenter {
+                            onenter.call(());
+                        }
+                    }
+                },
+            }
+            if let Some(error) = &props.error {
+                p { class: "text-xs text-red-600", role: "alert", "{error}" }
+            }
         }
     }
 }
@@ -0,0 +1,139 @@
+use crate::store::{
+    TreeState, CONTROLS, DUPLICATES, PLAYING, RBTREE, RED_BLACK_TREE, SELECTED_NODE, STATS, STATUS,
+    TREE_STATES,
+};
+use crate::timer::sleep;
+use dioxus::prelude::*;
+use rbt_core::stats::OperationStats;
+use rbt_core::trace::Operation;
+use rbt_core::tree::RBTree;
+use rbt_core::{narration, pseudocode};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+const MIN_STEP_MS: i32 = 100;
+
+// Bumped whenever playback starts or stops, so a stale playback loop knows to exit.
+static PLAYBACK: AtomicU32 = AtomicU32::new(0);
+
+pub fn is_at_latest() -> bool {
+    *CONTROLS.read().ind.read() == TREE_STATES.read().len() as i32 - 1
+}
+
+/// The recorded step currently on the canvas, if any.
+pub fn current() -> Option<TreeState> {
+    let ind = usize::try_from(*CONTROLS.read().ind.read()).ok()?;
+    TREE_STATES.read().get(ind).cloned()
+}
+
+pub fn describe(operation: Operation) -> String {
+    match operation {
+        Operation::Insert(key) => format!("Insert {key}"),
+        Operation::Delete(key) => format!("Delete {key}"),
+        Operation::Search(key) => format!("Search {key}"),
+    }
+}
+
+/// Applies `operation` to the working tree and appends every step it went through to the history.
+pub fn record(operation: Operation) {
+    let trace = RED_BLACK_TREE.write().apply_traced(operation);
+    if trace.steps.is_empty() {
+        return;
+    }
+    let highlights = pseudocode::annotate(&trace);
+    let sentences = narration::narrate(&trace);
+    let stats = OperationStats::new(&trace, &RED_BLACK_TREE.read());
+    STATS.write().push(stats);
+
+    TREE_STATES.write().extend(
+        trace
+            .steps
+            .into_iter()
+            .zip(highlights.into_iter().zip(sentences))
+            .enumerate()
+            .map(|(i, (step, (highlight, narration)))| TreeState {
+                operation,
+                step: i,
+                tree: step.tree,
+                highlight,
+                narration,
+            }),
+    );
+    go_to(TREE_STATES.read().len() as i32 - 1);
+}
+
+/// Shows the recorded step at `ind` on the canvas.
+pub fn go_to(ind: i32) {
+    let Some(state) = usize::try_from(ind)
+        .ok()
+        .and_then(|i| TREE_STATES.read().get(i).cloned())
+    else {
+        return;
+    };
+    show(state.tree);
+    *STATUS.write() = state.narration;
+    CONTROLS.write().ind.set(ind);
+}
+
+// Puts `tree` on the canvas. The selection goes with the old tree: its pointer may name a
+// different node in the new one once a slot has been reused.
+fn show(tree: RBTree) {
+    *RBTREE.write() = tree;
+    *SELECTED_NODE.write() = None;
+}
+
+/// Time between steps during playback, driven by the speed slider.
+pub fn step_delay() -> i32 {
+    MIN_STEP_MS + *CONTROLS.read().speed.read() * 10
+}
+
+/// Steps through the history from the current index, restarting from the beginning when
+/// already at the end. Calling it again while playing pauses.
+pub fn toggle_playback() {
+    let playback = PLAYBACK.fetch_add(1, Ordering::Relaxed) + 1;
+    if *PLAYING.peek() {
+        *PLAYING.write() = false;
+        return;
+    }
+    if is_at_latest() {
+        go_to(0);
+    }
+    *PLAYING.write() = true;
+    spawn(async move {
+        while !is_at_latest() {
+            sleep(step_delay()).await;
+            if PLAYBACK.load(Ordering::Relaxed) != playback {
+                return;
+            }
+            go_to(*CONTROLS.peek().ind.peek() + 1);
+        }
+        *PLAYING.write() = false;
+    });
+}
+
+/// Replaces the history with `demo`, recorded on top of `setup`, and shows the tree as it was
+/// before the first recorded step.
+pub fn load(setup: &[Operation], demo: &[Operation]) {
+    clear();
+    for &operation in setup {
+        RED_BLACK_TREE.write().apply(operation);
+    }
+    let start = RED_BLACK_TREE.read().snapshot();
+    for &operation in demo {
+        record(operation);
+    }
+    show(start);
+    CONTROLS.write().ind.set(-1);
+    *STATUS.write() = "Step through the recorded operations".to_string();
+}
+
+pub fn clear() {
+    PLAYBACK.fetch_add(1, Ordering::Relaxed);
+    *PLAYING.write() = false;
+    *RED_BLACK_TREE.write() = RBTree::with_duplicates(*DUPLICATES.read());
+    RBTREE.write().clear_tree();
+    *SELECTED_NODE.write() = None;
+    TREE_STATES.write().clear();
+    STATS.write().clear();
+    CONTROLS.write().ind.set(-1);
+    *STATUS.write() = "IDLE".to_string();
+}
@@ -1,4 +1,7 @@
-use crate::{components::navbar::Navbar, Route};
+use crate::{
+    components::{navbar::Navbar, shortcuts::Shortcuts},
+    Route,
+};
 use dioxus::prelude::*;
 
 #[component]
@@ -6,6 +9,7 @@ pub fn Layout() -> Element {
     rsx! {
         div { class: "flex flex-col h-screen bg-zinc-50",
             Navbar {}
+            Shortcuts {}
             div { class: "flex flex-col flex-1", Router::<Route> {} }
         }
     }
@@ -0,0 +1,153 @@
+use rbt_core::scene::SceneOptions;
+
+/// One page of the `/learn` tutorial. `setup` builds the starting tree without recording it;
+/// `demo` is recorded so the learner can step through it. Both are operation scripts.
+pub struct Lesson {
+    pub title: &'static str,
+    pub paragraphs: &'static [&'static str],
+    pub setup: &'static str,
+    pub demo: &'static str,
+    pub options: SceneOptions,
+}
+
+const PLAIN: SceneOptions = SceneOptions {
+    nil_leaves: false,
+    black_height: false,
+    size: false,
+    sum: false,
+    min_max: false,
+    intervals: false,
+};
+
+const WITH_NIL: SceneOptions = SceneOptions {
+    nil_leaves: true,
+    black_height: true,
+    size: false,
+    sum: false,
+    min_max: false,
+    intervals: false,
+};
+
+pub const LESSONS: &[Lesson] = &[
+    Lesson {
+        title: "Binary search trees",
+        paragraphs: &[
+            "A binary search tree keeps every key in the left subtree of a node smaller than the node, and every key in the right subtree larger.",
+            "Searching starts at the root and goes left or right after each comparison, so it takes as many steps as the tree is tall.",
+            "Step through a search that finds 60, then one for 45, which ends at a NIL child.",
+        ],
+        setup: "insert 50 30 70 20 40 60 80",
+        demo: "search 60\nsearch 45",
+        options: PLAIN,
+    },
+    Lesson {
+        title: "Colors and the five rules",
+        paragraphs: &[
+            "Every node is red or black, the root is black, and the NIL leaves are black.",
+            "A red node never has a red child, and every path from a node down to a NIL leaf passes through the same number of black nodes: its black height (bh).",
+            "Together these rules keep the height below 2·log₂(n+1). The NIL leaves and black heights are switched on for this lesson. Step through inserting 3: its parent 5 is black, so the new red leaf breaks no rule.",
+        ],
+        setup: "insert 10 5 20 1 15 25\ndelete 1",
+        demo: "insert 3",
+        options: WITH_NIL,
+    },
+    Lesson {
+        title: "Rotations",
+        paragraphs: &[
+            "A rotation swaps a node with one of its children while keeping the keys in search-tree order.",
+            "Rotating left at x moves x's right child y up into x's place; x becomes y's left child, and y's old left subtree becomes x's right subtree.",
+            "Inserting 10, 20 and 30 in order builds a chain that a single left rotation at 10 balances.",
+        ],
+        setup: "",
+        demo: "insert 10 20 30",
+        options: PLAIN,
+    },
+    Lesson {
+        title: "Insert case 1: red uncle",
+        paragraphs: &[
+            "A new key always starts as a red leaf. If its parent is red too, RB-INSERT-FIXUP looks at the uncle, the parent's sibling.",
+            "When the uncle is red, the parent and uncle turn black and the grandparent turns red. No rotation is needed, but the grandparent may now clash with its own parent, so the fixup continues from there.",
+            "Insert 1 below 5, whose sibling 15 is red.",
+        ],
+        setup: "insert 10 5 15",
+        demo: "insert 1",
+        options: PLAIN,
+    },
+    Lesson {
+        title: "Insert case 2: inner child",
+        paragraphs: &[
+            "When the uncle is black and the new node is an inner child (the right child of a left child, or the other way round), the parent and child form a triangle.",
+            "Rotating at the parent turns the triangle into a line, which case 3 then finishes.",
+            "Insert 7 as the right child of 5, with no uncle on the other side.",
+        ],
+        setup: "insert 10 5",
+        demo: "insert 7",
+        options: PLAIN,
+    },
+    Lesson {
+        title: "Insert case 3: outer child",
+        paragraphs: &[
+            "When the uncle is black and the new node is an outer child, the parent is colored black, the grandparent red, and the grandparent is rotated the other way.",
+            "The parent ends up where the grandparent was, black, so the fixup is finished.",
+            "Insert 1 as the left child of 5.",
+        ],
+        setup: "insert 10 5",
+        demo: "insert 1",
+        options: PLAIN,
+    },
+    Lesson {
+        title: "Deleting a node",
+        paragraphs: &[
+            "A node with at most one child is replaced by that child. A node with two children is replaced by its successor, the smallest key in its right subtree, which takes over its color.",
+            "Removing a red node never breaks a rule. Removing a black one leaves its replacement doubly black, which RB-DELETE-FIXUP repairs.",
+            "Delete the red leaf 3, then 10, which has two children.",
+        ],
+        setup: "insert 10 5 15 3",
+        demo: "delete 3\ndelete 10",
+        options: PLAIN,
+    },
+    Lesson {
+        title: "Delete case 1: red sibling",
+        paragraphs: &[
+            "The doubly black node x is looked at together with its sibling w.",
+            "If w is red, w turns black, the parent turns red and the parent is rotated towards x. x now has a black sibling, so one of the other cases applies.",
+            "Delete 5: its sibling 20 is red.",
+        ],
+        setup: "insert 10 5 20 15 25 30",
+        demo: "delete 5",
+        options: WITH_NIL,
+    },
+    Lesson {
+        title: "Delete case 2: black nephews",
+        paragraphs: &[
+            "If the sibling w is black and both of its children are black, w turns red. That takes one black off both sides, so the extra black moves up to the parent.",
+            "A red parent simply turns black; a black one becomes the new doubly black node.",
+            "Delete 5 from a tree of three black nodes.",
+        ],
+        setup: "insert 10 5 20 1\ndelete 1",
+        demo: "delete 5",
+        options: WITH_NIL,
+    },
+    Lesson {
+        title: "Delete case 3: near nephew red",
+        paragraphs: &[
+            "If w is black, its far child is black but its near child is red, w and its near child swap colors and w is rotated away from x.",
+            "Now the far nephew is red, which is case 4.",
+            "Delete 5: its sibling 20 has a red left child, 15.",
+        ],
+        setup: "insert 10 5 20 15",
+        demo: "delete 5",
+        options: WITH_NIL,
+    },
+    Lesson {
+        title: "Delete case 4: far nephew red",
+        paragraphs: &[
+            "If w is black and its far child is red, w takes the parent's color, the parent and the far nephew turn black, and the parent is rotated towards x.",
+            "That adds a black node above x, so the extra black is absorbed and the fixup ends.",
+            "Delete 5: its sibling 20 has a red right child, 25.",
+        ],
+        setup: "insert 10 5 20 25",
+        demo: "delete 5",
+        options: WITH_NIL,
+    },
+];
@@ -1,21 +1,38 @@
 #![allow(non_snake_case)]
 
-mod algorithm;
 mod components;
+mod history;
 mod layout;
+mod lessons;
 mod pages;
 mod store;
+mod timer;
 
 use dioxus::prelude::*;
 use layout::Layout;
 use pages::about::About;
 use pages::home::Home;
+use pages::intervals::Intervals;
+use pages::learn::Learn;
 use pages::not_found::NotFound;
+use pages::quiz::Quiz;
+use pages::sandbox::Sandbox;
+use pages::stats::Stats;
 
 #[derive(Routable, PartialEq, Clone)]
 enum Route {
     #[route("/")]
     Home {},
+    #[route("/intervals")]
+    Intervals {},
+    #[route("/learn")]
+    Learn {},
+    #[route("/quiz")]
+    Quiz {},
+    #[route("/sandbox")]
+    Sandbox {},
+    #[route("/stats")]
+    Stats {},
     #[route("/about")]
     About {},
     #[route("/:..segments")]
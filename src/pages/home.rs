@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
 
-use crate::components::{canvas::Canvas, controls::Controls};
+use crate::components::{
+    canvas::Canvas, controls::Controls, log::Log, pseudocode::Pseudocode, timeline::Timeline,
+};
 
 #[component]
 pub fn Home() -> Element {
@@ -8,7 +10,14 @@ pub fn Home() -> Element {
         div { class: "flex flex-col justify-end items-center h-screen",
             div { class: "flex h-[calc(100vh-3.5rem)] flex-row max-sm:flex-col w-full p-1 z-50 gap-1",
                 Controls {}
-                Canvas {}
+                div { class: "flex flex-col gap-1 flex-1 min-w-0 max-sm:min-h-[75%]",
+                    Canvas {}
+                    Timeline {}
+                }
+                div { class: "flex flex-col gap-1 w-1/4 max-sm:w-full min-h-0",
+                    Pseudocode {}
+                    Log {}
+                }
             }
         }
     }
@@ -0,0 +1,325 @@
+use dioxus::prelude::*;
+use rbt_core::interval::{Interval, IntervalTree};
+use rbt_core::scene::SceneOptions;
+use rbt_core::tree::{Pointer, RBTree};
+
+use crate::components::tree_view::TreeView;
+use crate::components::ui::button::Button;
+use crate::components::ui::input::Input;
+
+const MAX_KEY: f64 = 99.0;
+const MAX_LENGTH: f64 = 20.0;
+const RANDOM_INTERVALS: usize = 8;
+
+const OPTIONS: SceneOptions = SceneOptions {
+    nil_leaves: false,
+    black_height: false,
+    size: false,
+    sum: false,
+    min_max: false,
+    intervals: true,
+};
+
+// Accepts "lo hi" or "lo, hi".
+fn parse(text: &str) -> Result<Interval, String> {
+    let numbers: Vec<&str> = text
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect();
+    match numbers.as_slice() {
+        [lo, hi] => match (lo.parse::<i32>(), hi.parse::<i32>()) {
+            (Ok(lo), Ok(hi)) => Ok(Interval::new(lo, hi)),
+            _ => Err(format!("\"{text}\" is not two whole numbers")),
+        },
+        _ => Err("Enter two endpoints, like 15 23".to_string()),
+    }
+}
+
+fn random_interval() -> Interval {
+    let lo = (js_sys::Math::random() * MAX_KEY) as i32;
+    Interval::new(lo, lo + (js_sys::Math::random() * MAX_LENGTH) as i32)
+}
+
+/// What the last overlap query found.
+#[derive(Clone, PartialEq)]
+struct Query {
+    interval: Interval,
+    // INTERVAL-SEARCH's path and the node it stopped at, if any.
+    path: Vec<Interval>,
+    found: Option<Interval>,
+    overlaps: Vec<Pointer>,
+}
+
+fn list(intervals: impl Iterator<Item = Interval>) -> String {
+    let items: Vec<String> = intervals.map(|interval| interval.to_string()).collect();
+    if items.is_empty() {
+        "none".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
+/// Interval tree playground: the red-black tree keyed on low endpoints, with every node's
+/// largest endpoint below it, and overlap queries against it.
+#[component]
+pub fn Intervals() -> Element {
+    let mut intervals = use_signal(IntervalTree::new);
+    let mut board: Signal<RBTree> = use_signal(|| intervals.peek().tree().clone());
+    let mut text = use_signal(String::new);
+    let mut text_error: Signal<Option<String>> = use_signal(|| None);
+    let mut query_text = use_signal(String::new);
+    let mut query_error: Signal<Option<String>> = use_signal(|| None);
+    let mut query: Signal<Option<Query>> = use_signal(|| None);
+
+    // Redraws the board after `intervals` changed; the old query no longer applies.
+    let mut refresh = move || {
+        board.set(intervals.read().tree().clone());
+        query.set(None);
+    };
+
+    let mut insert = move || {
+        let parsed = parse(&text.read());
+        match parsed {
+            Ok(interval) => {
+                intervals.write().insert(interval);
+                refresh();
+                text.set(String::new());
+                text_error.set(None);
+            }
+            Err(err) => text_error.set(Some(err)),
+        }
+    };
+
+    let mut remove = move || {
+        let parsed = parse(&text.read());
+        match parsed {
+            Ok(interval) if intervals.write().remove(interval) => {
+                refresh();
+                text.set(String::new());
+                text_error.set(None);
+            }
+            Ok(interval) => text_error.set(Some(format!("{interval} is not in the tree"))),
+            Err(err) => text_error.set(Some(err)),
+        }
+    };
+
+    let mut search = move || {
+        let parsed = parse(&query_text.read());
+        match parsed {
+            Ok(interval) => {
+                let tree = intervals.read();
+                let (found, path) = tree.search(interval);
+                query.set(Some(Query {
+                    interval,
+                    path: path.iter().map(|&node| tree.interval(node)).collect(),
+                    found: (!found.is_null()).then(|| tree.interval(found)),
+                    overlaps: tree.overlapping(interval),
+                }));
+                query_error.set(None);
+            }
+            Err(err) => query_error.set(Some(err)),
+        }
+    };
+
+    let marked = query
+        .read()
+        .as_ref()
+        .map(|query| query.overlaps.clone())
+        .unwrap_or_default();
+
+    rsx! {
+        div { class: "flex flex-col justify-end items-center h-screen",
+            div { class: "flex flex-row max-sm:flex-col h-[calc(100vh-3.5rem)] w-full p-1 gap-1",
+                div { class: "flex flex-col gap-5 w-1/4 max-sm:w-full bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg rounded-xl p-6 border border-slate-200 overflow-y-auto",
+                    h2 { class: "text-xl font-semibold text-slate-700", "Interval Tree" }
+                    p { class: "text-sm text-slate-600",
+                        "Each node holds an interval and is keyed on its low end. Next to it is the largest high end in its subtree, which rotations keep up to date and overlap queries use to skip whole subtrees."
+                    }
+
+                    div { class: "flex flex-col gap-3",
+                        label { class: "text-sm font-medium text-slate-600", "Interval" }
+                        Input {
+                            value: text.read().clone(),
+                            error: text_error.read().clone(),
+                            placeholder: "Low and high, e.g. 15 23",
+                            oninput: move |value| text.set(value),
+                            onenter: move |_| insert(),
+                        }
+                        div { class: "grid grid-cols-2 gap-2",
+                            Button {
+                                value: "Insert",
+                                color: Some("bg-emerald-500 hover:bg-emerald-600 active:bg-emerald-700".to_string()),
+                                onclick: move |_| insert(),
+                            }
+                            Button {
+                                value: "Remove",
+                                color: Some("bg-red-500 hover:bg-red-600 active:bg-red-700".to_string()),
+                                onclick: move |_| remove(),
+                            }
+                        }
+                    }
+
+                    div { class: "flex flex-col gap-3",
+                        label { class: "text-sm font-medium text-slate-600", "Overlap Query" }
+                        Input {
+                            value: query_text.read().clone(),
+                            error: query_error.read().clone(),
+                            placeholder: "Query interval, e.g. 8 16",
+                            oninput: move |value| query_text.set(value),
+                            onenter: move |_| search(),
+                        }
+                        Button {
+                            value: "Find overlaps",
+                            color: Some("bg-blue-500 hover:bg-blue-600 active:bg-blue-700".to_string()),
+                            onclick: move |_| search(),
+                        }
+                        if let Some(query) = query.read().as_ref() {
+                            div { class: "flex flex-col gap-1 text-xs text-slate-600",
+                                p {
+                                    "INTERVAL-SEARCH visited {list(query.path.iter().copied())} and "
+                                    match query.found {
+                                        Some(found) => format!("stopped at {found}."),
+                                        None => "found no overlap.".to_string(),
+                                    }
+                                }
+                                p {
+                                    "All intervals overlapping {query.interval}: "
+                                    span { class: "font-semibold text-red-600",
+                                        "{list(query.overlaps.iter().map(|&node| intervals.read().interval(node)))}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "grid grid-cols-2 gap-2 mt-auto",
+                        Button {
+                            value: "Random",
+                            color: Some("bg-indigo-500 hover:bg-indigo-600".to_string()),
+                            onclick: move |_| {
+                                let mut tree = IntervalTree::new();
+                                for _ in 0..RANDOM_INTERVALS {
+                                    tree.insert(random_interval());
+                                }
+                                intervals.set(tree);
+                                refresh();
+                            },
+                        }
+                        Button {
+                            value: "Clear",
+                            color: Some("bg-slate-500 hover:bg-slate-600".to_string()),
+                            onclick: move |_| {
+                                intervals.set(IntervalTree::new());
+                                refresh();
+                            },
+                        }
+                    }
+                }
+
+                div { class: "flex flex-col gap-1 flex-1 min-w-0",
+                    div { class: "flex flex-col flex-1 min-h-0 rounded-xl bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg p-4 border border-slate-200",
+                        TreeView { tree: board, marked: marked.clone(), options: OPTIONS }
+                    }
+                    NumberLine { tree: board, query: query.read().as_ref().map(|query| query.interval), marked }
+                }
+            }
+        }
+    }
+}
+
+const ROW: f64 = 6.0;
+
+/// Every stored interval on one axis, in key order, with the query shaded behind them.
+#[component]
+fn NumberLine(
+    tree: ReadOnlySignal<RBTree>,
+    query: Option<Interval>,
+    marked: Vec<Pointer>,
+) -> Element {
+    let tree = tree.read();
+    let mut rows = Vec::new();
+    let mut node = if tree.is_empty() {
+        Pointer::null()
+    } else {
+        tree.minimum(tree.root)
+    };
+    while !node.is_null() {
+        rows.push((node, tree[node].value, tree[node].high));
+        node = tree.successor(node);
+    }
+    if rows.is_empty() {
+        return rsx! {};
+    }
+
+    let lo = rows
+        .iter()
+        .map(|&(_, lo, _)| lo)
+        .chain(query.map(|query| query.lo))
+        .min()
+        .unwrap_or(0) as f64;
+    let hi = rows
+        .iter()
+        .map(|&(_, _, hi)| hi)
+        .chain(query.map(|query| query.hi))
+        .max()
+        .unwrap_or(0) as f64;
+    let span = (hi - lo).max(1.0);
+    let width = 200.0;
+    let x = move |value: i32| (value as f64 - lo) / span * width;
+    let height = rows.len() as f64 * ROW + 8.0;
+
+    rsx! {
+        div { class: "rounded-xl bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg p-4 border border-slate-200 max-h-64 overflow-y-auto",
+            svg {
+                width: "100%",
+                view_box: "-10 -2 {width + 20.0} {height}",
+                if let Some(query) = query {
+                    rect {
+                        x: "{x(query.lo)}",
+                        y: "-2",
+                        width: "{(x(query.hi) - x(query.lo)).max(0.5)}",
+                        height: "{height}",
+                        fill: "#bfdbfe",
+                        opacity: "0.6",
+                    }
+                }
+                for (i , & (node , lo , hi)) in rows.iter().enumerate() {
+                    g { key: "{node:?}",
+                        line {
+                            x1: "{x(lo)}",
+                            y1: "{i as f64 * ROW + 2.0}",
+                            x2: "{x(hi)}",
+                            y2: "{i as f64 * ROW + 2.0}",
+                            stroke: if marked.contains(&node) { "#dc2626" } else { "#475569" },
+                            stroke_width: "2",
+                            stroke_linecap: "round",
+                        }
+                        text {
+                            x: "{x(hi) + 2.0}",
+                            y: "{i as f64 * ROW + 3.5}",
+                            font_size: "4",
+                            fill: "#334155",
+                            "[{lo}, {hi}]"
+                        }
+                    }
+                }
+                text {
+                    x: "0",
+                    y: "{height - 3.0}",
+                    font_size: "4",
+                    fill: "#94a3b8",
+                    text_anchor: "middle",
+                    "{lo}"
+                }
+                text {
+                    x: "{width}",
+                    y: "{height - 3.0}",
+                    font_size: "4",
+                    fill: "#94a3b8",
+                    text_anchor: "middle",
+                    "{hi}"
+                }
+            }
+        }
+    }
+}
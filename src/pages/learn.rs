@@ -0,0 +1,115 @@
+use dioxus::prelude::*;
+use rbt_core::export::script;
+
+use crate::components::{
+    canvas::Canvas, log::Log, pseudocode::Pseudocode, timeline::Timeline, ui::button::Button,
+};
+use crate::history;
+use crate::lessons::LESSONS;
+use crate::store::{CONTROLS, PLAYING, SCENE_OPTIONS, TREE_STATES};
+
+/// Loads lesson `index` onto the canvas, replacing the current history.
+fn open(index: usize) {
+    let lesson = &LESSONS[index];
+    let setup = script::parse(lesson.setup).unwrap_or_default();
+    let demo = script::parse(lesson.demo).unwrap_or_default();
+    *SCENE_OPTIONS.write() = lesson.options;
+    history::load(&setup, &demo);
+}
+
+/// The guided tutorial: one lesson at a time, each with a scripted history to step through.
+#[component]
+pub fn Learn() -> Element {
+    let mut current = use_signal(|| 0);
+    let mut show = move |index: usize| {
+        current.set(index);
+        open(index);
+    };
+
+    // Not an effect: loading writes to signals it also reads, which would re-run it on every step.
+    use_future(move || async move { open(0) });
+
+    let index = current();
+    let lesson = &LESSONS[index];
+    let ind = *CONTROLS.read().ind.read();
+    let steps = TREE_STATES.read().len() as i32;
+
+    rsx! {
+        div { class: "flex flex-col justify-end items-center h-screen",
+            div { class: "flex h-[calc(100vh-3.5rem)] flex-row max-sm:flex-col w-full p-1 z-50 gap-1",
+                div { class: "flex flex-col gap-4 w-1/4 max-sm:w-full bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg rounded-xl p-6 border border-slate-200 overflow-y-auto",
+                    span { class: "text-xs font-semibold uppercase tracking-wide text-blue-600",
+                        "Lesson {index + 1} of {LESSONS.len()}"
+                    }
+                    h2 { class: "text-xl font-semibold text-slate-700", "{lesson.title}" }
+                    for paragraph in lesson.paragraphs.iter() {
+                        p { class: "text-sm text-slate-600 leading-relaxed", "{paragraph}" }
+                    }
+
+                    div { class: "grid grid-cols-3 gap-2",
+                        Button {
+                            value: "⟸ Step",
+                            color: Some("bg-blue-500 hover:bg-blue-600 active:bg-blue-700".to_string()),
+                            disabled: ind < 0,
+                            onclick: move |_| {
+                                let ind = *CONTROLS.read().ind.read();
+                                if ind == 0 {
+                                    open(current());
+                                } else {
+                                    history::go_to(ind - 1);
+                                }
+                            },
+                        }
+                        Button {
+                            value: if *PLAYING.read() { "Pause" } else { "Play" },
+                            color: Some("bg-indigo-500 hover:bg-indigo-600 active:bg-indigo-700".to_string()),
+                            disabled: steps == 0,
+                            onclick: move |_| history::toggle_playback(),
+                        }
+                        Button {
+                            value: "Step ⟹",
+                            color: Some("bg-blue-500 hover:bg-blue-600 active:bg-blue-700".to_string()),
+                            disabled: ind >= steps - 1,
+                            onclick: move |_| history::go_to(*CONTROLS.read().ind.read() + 1),
+                        }
+                    }
+
+                    div { class: "grid grid-cols-2 gap-2 mt-auto pt-4 border-t border-slate-300",
+                        Button {
+                            value: "Previous lesson",
+                            color: Some("bg-slate-500 hover:bg-slate-600 active:bg-slate-700".to_string()),
+                            disabled: index == 0,
+                            onclick: move |_| show(index - 1),
+                        }
+                        Button {
+                            value: "Next lesson",
+                            color: Some("bg-emerald-500 hover:bg-emerald-600 active:bg-emerald-700".to_string()),
+                            disabled: index + 1 == LESSONS.len(),
+                            onclick: move |_| show(index + 1),
+                        }
+                    }
+                    ol { class: "flex flex-col gap-1 text-sm list-decimal pl-5",
+                        for (i , lesson) in LESSONS.iter().enumerate() {
+                            li {
+                                class: if i == index { "text-blue-600 font-semibold" } else { "text-slate-500 hover:text-slate-700 cursor-pointer" },
+                                onclick: move |_| show(i),
+                                "{lesson.title}"
+                            }
+                        }
+                    }
+                    a { class: "text-sm text-blue-600 hover:underline", href: "/",
+                        "Keep experimenting with this tree in the visualizer →"
+                    }
+                }
+                div { class: "flex flex-col gap-1 flex-1 min-w-0 max-sm:min-h-[75%]",
+                    Canvas {}
+                    Timeline {}
+                }
+                div { class: "flex flex-col gap-1 w-1/4 max-sm:w-full min-h-0",
+                    Pseudocode {}
+                    Log {}
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,8 @@
 pub mod about;
 pub mod home;
+pub mod intervals;
+pub mod learn;
 pub mod not_found;
+pub mod quiz;
+pub mod sandbox;
+pub mod stats;
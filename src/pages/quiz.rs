@@ -0,0 +1,295 @@
+use dioxus::prelude::*;
+use rbt_core::quiz::{Grade, Question, Rotation};
+use rbt_core::trace::Case;
+use rbt_core::tree::{Pointer, RBTree};
+
+use crate::components::tree_view::TreeView;
+use crate::components::ui::button::Button;
+
+const MAX_KEY: f64 = 99.0;
+const START_KEYS: usize = 6;
+// Once the tree gets this big it is replaced by a fresh one, to keep the board readable.
+const MAX_NODES: usize = 15;
+
+const CASES: &[(Case, &str)] = &[
+    (Case::InsertRedUncle, "Case 1: red uncle, recolor"),
+    (
+        Case::InsertTriangle,
+        "Case 2: inner child, rotate the parent",
+    ),
+    (
+        Case::InsertLine,
+        "Case 3: outer child, recolor and rotate the grandparent",
+    ),
+];
+
+fn random_key() -> i32 {
+    (js_sys::Math::random() * MAX_KEY) as i32 + 1
+}
+
+fn random_tree() -> RBTree {
+    let mut tree = RBTree::new();
+    while tree.len() < START_KEYS {
+        tree.insert(random_key());
+    }
+    tree
+}
+
+// A question about inserting a random key that is not in `tree` yet.
+fn ask(tree: &RBTree) -> Question {
+    let tree = if tree.len() >= MAX_NODES {
+        random_tree()
+    } else {
+        tree.clone()
+    };
+    loop {
+        if let Some(question) = Question::new(&tree, random_key()) {
+            return question;
+        }
+    }
+}
+
+fn case_name(case: Case) -> Option<&'static str> {
+    CASES
+        .iter()
+        .find(|(c, _)| *c == case)
+        .map(|(_, name)| *name)
+}
+
+fn rotation_name(rotation: Rotation) -> String {
+    match rotation {
+        Rotation::Left(key) => format!("left at {key}"),
+        Rotation::Right(key) => format!("right at {key}"),
+    }
+}
+
+fn list(items: Vec<String>) -> String {
+    if items.is_empty() {
+        "none".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
+/// Practice mode: predict how inserting a key fixes the tree up, then compare with the real thing.
+#[component]
+pub fn Quiz() -> Element {
+    let mut question = use_signal(|| ask(&random_tree()));
+    let mut board = use_signal(|| question.peek().start.clone());
+    let mut selected: Signal<Option<Pointer>> = use_signal(|| None);
+    let mut cases: Signal<Vec<Case>> = use_signal(Vec::new);
+    let mut rotations: Signal<Vec<Rotation>> = use_signal(Vec::new);
+    let mut grade: Signal<Option<Grade>> = use_signal(|| None);
+    let mut score = use_signal(|| (0, 0));
+    let mut answer = use_signal(|| question.peek().answer.clone());
+
+    let mut reset = move || {
+        board.set(question.peek().start.clone());
+        selected.set(None);
+        rotations.write().clear();
+    };
+
+    let mut next = move || {
+        let next = ask(&question.peek().answer);
+        answer.set(next.answer.clone());
+        question.set(next);
+        cases.write().clear();
+        grade.set(None);
+        reset();
+    };
+
+    let mut rotate = move |rotation: Rotation| {
+        rotation.apply(&mut board.write());
+        rotations.write().push(rotation);
+    };
+
+    let check = move |_| {
+        let result = question
+            .read()
+            .grade(&cases.read(), &rotations.read(), &board.read());
+        let (correct, attempts) = *score.read();
+        score.set((correct + result.is_correct() as u32, attempts + 1));
+        grade.set(Some(result));
+        selected.set(None);
+    };
+
+    let selected_key = selected().map(|node| board.read()[node].value);
+    let answered = grade.read().is_some();
+    let (correct, attempts) = score();
+    let key = question.read().key;
+
+    rsx! {
+        div { class: "flex flex-col justify-end items-center h-screen",
+            div { class: "flex flex-row max-sm:flex-col h-[calc(100vh-3.5rem)] w-full p-1 gap-1",
+                div { class: "flex flex-col gap-4 w-1/4 max-sm:w-full bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg rounded-xl p-6 border border-slate-200 overflow-y-auto",
+                    div { class: "flex items-center justify-between",
+                        h2 { class: "text-xl font-semibold text-slate-700", "Predict the Insert" }
+                        span { class: "px-2 py-1 bg-blue-100 text-blue-600 rounded-md text-xs font-semibold",
+                            "Score {correct} / {attempts}"
+                        }
+                    }
+                    p { class: "text-sm text-slate-600",
+                        "{key} has just been attached as a red leaf. Choose the cases that fire, then click nodes to recolor and rotate them until the tree matches what the fixup produces."
+                    }
+
+                    div { class: "flex flex-col gap-2",
+                        label { class: "text-sm font-medium text-slate-600", "Which cases fire?" }
+                        for (case , name) in CASES.iter().copied() {
+                            label { class: "flex items-start gap-2 text-sm text-slate-600 cursor-pointer",
+                                input {
+                                    r#type: "checkbox",
+                                    class: "accent-blue-500 mt-1",
+                                    disabled: answered,
+                                    checked: cases.read().contains(&case),
+                                    onchange: move |e| {
+                                        if e.checked() {
+                                            cases.write().push(case);
+                                        } else {
+                                            cases.write().retain(|c| *c != case);
+                                        }
+                                    },
+                                }
+                                "{name}"
+                            }
+                        }
+                        p { class: "text-xs text-slate-400", "Leave all unchecked if no fixup is needed." }
+                    }
+
+                    div { class: "flex flex-col gap-2",
+                        label { class: "text-sm font-medium text-slate-600",
+                            match selected_key {
+                                Some(key) => format!("Node {key}"),
+                                None => "Click a node to change it".to_string(),
+                            }
+                        }
+                        div { class: "grid grid-cols-3 gap-2",
+                            Button {
+                                value: "Color",
+                                color: Some("bg-slate-600 hover:bg-slate-700".to_string()),
+                                disabled: selected().is_none() || answered,
+                                onclick: move |_| {
+                                    if let Some(node) = selected() {
+                                        board.write().toggle_color(node);
+                                    }
+                                },
+                            }
+                            Button {
+                                value: "⟲ Left",
+                                color: Some("bg-blue-500 hover:bg-blue-600".to_string()),
+                                disabled: selected().is_none() || answered,
+                                onclick: move |_| {
+                                    if let Some(key) = selected_key {
+                                        rotate(Rotation::Left(key));
+                                    }
+                                },
+                            }
+                            Button {
+                                value: "Right ⟳",
+                                color: Some("bg-blue-500 hover:bg-blue-600".to_string()),
+                                disabled: selected().is_none() || answered,
+                                onclick: move |_| {
+                                    if let Some(key) = selected_key {
+                                        rotate(Rotation::Right(key));
+                                    }
+                                },
+                            }
+                        }
+                        p { class: "text-xs text-slate-500",
+                            "Rotations so far: {list(rotations.read().iter().map(|r| rotation_name(*r)).collect())}"
+                        }
+                    }
+
+                    div { class: "grid grid-cols-2 gap-2 mt-auto",
+                        Button {
+                            value: "Start over",
+                            color: Some("bg-slate-500 hover:bg-slate-600".to_string()),
+                            disabled: answered,
+                            onclick: move |_| reset(),
+                        }
+                        if answered {
+                            Button {
+                                value: "Next question",
+                                color: Some("bg-emerald-500 hover:bg-emerald-600".to_string()),
+                                onclick: move |_| next(),
+                            }
+                        } else {
+                            Button {
+                                value: "Check",
+                                color: Some("bg-emerald-500 hover:bg-emerald-600".to_string()),
+                                onclick: check,
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex flex-col gap-1 flex-1 min-w-0",
+                    div { class: "flex flex-1 min-h-0 gap-1",
+                        div { class: "flex flex-col flex-1 min-w-0 rounded-xl bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg p-4 border border-slate-200",
+                            h3 { class: "text-sm font-semibold text-slate-600", "Your prediction" }
+                            TreeView {
+                                tree: board,
+                                selected: selected(),
+                                onselect: move |node| {
+                                    if !answered {
+                                        selected.set(Some(node));
+                                    }
+                                },
+                            }
+                        }
+                        if answered {
+                            div { class: "flex flex-col flex-1 min-w-0 rounded-xl bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg p-4 border border-slate-200",
+                                h3 { class: "text-sm font-semibold text-slate-600", "What RB-INSERT does" }
+                                TreeView { tree: answer }
+                            }
+                        }
+                    }
+                    if let Some(grade) = grade() {
+                        Feedback { grade, question }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Feedback(grade: Grade, question: ReadOnlySignal<Question>) -> Element {
+    let question = question.read();
+    let verdict = |ok: bool| if ok { "✓" } else { "✗" };
+    let cases = list(
+        question
+            .cases
+            .iter()
+            .filter_map(|case| case_name(*case).map(str::to_string))
+            .collect(),
+    );
+    let rotations = list(
+        question
+            .rotations
+            .iter()
+            .map(|r| rotation_name(*r))
+            .collect(),
+    );
+    let colors = list(
+        grade
+            .wrong_colors
+            .iter()
+            .map(|key| key.to_string())
+            .collect(),
+    );
+
+    rsx! {
+        div {
+            class: if grade.is_correct() { "rounded-xl shadow-lg p-4 border border-emerald-300 bg-emerald-50 text-sm text-slate-700" } else { "rounded-xl shadow-lg p-4 border border-amber-300 bg-amber-50 text-sm text-slate-700" },
+            p { class: "font-semibold mb-2",
+                if grade.is_correct() { "Correct!" } else { "Not quite — compare your tree with the real result." }
+            }
+            ul { class: "flex flex-col gap-1",
+                li { "{verdict(grade.cases)} Cases: {cases}" }
+                li { "{verdict(grade.rotations)} Rotations: {rotations}" }
+                li { "{verdict(grade.shape)} Shape" }
+                li { "{verdict(grade.wrong_colors.is_empty())} Colors (wrong: {colors})" }
+            }
+        }
+    }
+}
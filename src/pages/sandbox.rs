@@ -0,0 +1,289 @@
+use dioxus::prelude::*;
+use rbt_core::edit::{EditError, Side};
+use rbt_core::invariants::Violation;
+use rbt_core::tree::{Pointer, RBTree};
+
+use crate::components::tree_view::TreeView;
+use crate::components::ui::button::Button;
+use crate::components::ui::input::Input;
+use crate::store::RBTREE;
+
+const MAX_KEY: f64 = 99.0;
+const RANDOM_KEYS: usize = 8;
+
+// The board plus everything needed to undo edits and report refused ones.
+#[derive(Copy, Clone)]
+struct Workbench {
+    board: Signal<RBTree>,
+    undo: Signal<Vec<RBTree>>,
+    message: Signal<Option<String>>,
+}
+
+impl Workbench {
+    fn edit(self, edit: impl FnOnce(&mut RBTree)) {
+        self.try_edit(|tree| {
+            edit(tree);
+            Ok(())
+        });
+    }
+
+    fn try_edit(mut self, edit: impl FnOnce(&mut RBTree) -> Result<(), EditError>) {
+        let before = self.board.peek().clone();
+        match edit(&mut self.board.write()) {
+            Ok(()) => {
+                self.undo.write().push(before);
+                self.message.set(None);
+            }
+            Err(err) => self.message.set(Some(err.to_string())),
+        }
+    }
+
+    fn load(mut self, tree: RBTree) {
+        let before = self.board.peek().clone();
+        self.undo.write().push(before);
+        self.board.set(tree);
+        self.message.set(None);
+    }
+
+    fn undo(mut self) {
+        let previous = self.undo.write().pop();
+        if let Some(previous) = previous {
+            self.board.set(previous);
+            self.message.set(None);
+        }
+    }
+}
+
+fn random_tree() -> RBTree {
+    let mut tree = RBTree::new();
+    while tree.len() < RANDOM_KEYS {
+        tree.insert((js_sys::Math::random() * MAX_KEY) as i32 + 1);
+    }
+    tree
+}
+
+fn describe(tree: &RBTree, violation: Violation) -> String {
+    match violation.node() {
+        Some(node) if tree.get(node).is_some() => format!("{violation} (at {})", tree[node].value),
+        _ => violation.to_string(),
+    }
+}
+
+/// Free-form editing: rotate, recolor and move subtrees by hand while the red-black
+/// properties are checked after every change.
+#[component]
+pub fn Sandbox() -> Element {
+    let sandbox = Workbench {
+        board: use_signal(|| RBTREE.peek().snapshot()),
+        undo: use_signal(Vec::new),
+        message: use_signal(|| None),
+    };
+    let board = sandbox.board;
+    let mut selected: Signal<Option<Pointer>> = use_signal(|| None);
+    let mut moving: Signal<Option<Pointer>> = use_signal(|| None);
+    let mut key = use_signal(String::new);
+    let mut key_error: Signal<Option<String>> = use_signal(|| None);
+
+    let violations = board.read().violations();
+    let marked: Vec<Pointer> = violations
+        .iter()
+        .map(|violation| violation.node().unwrap_or(board.read().root))
+        .collect();
+    let selected_key = selected().map(|node| board.read()[node].value);
+    let moving_key = moving().map(|node| board.read()[node].value);
+
+    let mut attach = move || {
+        let text = key.read().trim().to_string();
+        match text.parse::<i32>() {
+            Ok(value) => {
+                sandbox.try_edit(|tree| tree.attach_leaf(value).map(|_| ()));
+                key.set(String::new());
+                key_error.set(None);
+            }
+            Err(_) => key_error.set(Some(format!("\"{text}\" is not a whole number"))),
+        }
+    };
+
+    let mut move_to = move |side: Side| {
+        if let (Some(node), Some(target)) = (moving(), selected()) {
+            sandbox.try_edit(|tree| tree.reattach(node, target, side));
+        }
+        moving.set(None);
+    };
+
+    rsx! {
+        div { class: "flex flex-col justify-end items-center h-screen",
+            div { class: "flex flex-row max-sm:flex-col h-[calc(100vh-3.5rem)] w-full p-1 gap-1",
+                div { class: "flex flex-col gap-5 w-1/4 max-sm:w-full bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg rounded-xl p-6 border border-slate-200 overflow-y-auto",
+                    h2 { class: "text-xl font-semibold text-slate-700", "Sandbox" }
+                    p { class: "text-sm text-slate-600",
+                        "Attach a key as a red leaf, then repair the tree yourself. Nodes breaking a rule are outlined in red."
+                    }
+
+                    div { class: "flex flex-col gap-3",
+                        label { class: "text-sm font-medium text-slate-600", "Attach Leaf" }
+                        div { class: "flex gap-2",
+                            Input {
+                                value: key.read().clone(),
+                                error: key_error.read().clone(),
+                                placeholder: "Key to attach...",
+                                oninput: move |value| key.set(value),
+                                onenter: move |_| attach(),
+                            }
+                            Button {
+                                value: "Attach",
+                                color: Some("bg-emerald-500 hover:bg-emerald-600 active:bg-emerald-700".to_string()),
+                                onclick: move |_| attach(),
+                            }
+                        }
+                    }
+
+                    div { class: "flex flex-col gap-2",
+                        label { class: "text-sm font-medium text-slate-600",
+                            match (moving_key, selected_key) {
+                                (Some(moving), Some(target)) => format!("Hang {moving} under {target}"),
+                                (Some(moving), None) => format!("Click the new parent for {moving}"),
+                                (None, Some(key)) => format!("Node {key}"),
+                                (None, None) => "Click a node to edit it".to_string(),
+                            }
+                        }
+                        if moving().is_some() {
+                            div { class: "grid grid-cols-3 gap-2",
+                                Button {
+                                    value: "As left",
+                                    color: Some("bg-blue-500 hover:bg-blue-600".to_string()),
+                                    disabled: selected().is_none(),
+                                    onclick: move |_| move_to(Side::Left),
+                                }
+                                Button {
+                                    value: "As right",
+                                    color: Some("bg-blue-500 hover:bg-blue-600".to_string()),
+                                    disabled: selected().is_none(),
+                                    onclick: move |_| move_to(Side::Right),
+                                }
+                                Button {
+                                    value: "Cancel",
+                                    color: Some("bg-slate-500 hover:bg-slate-600".to_string()),
+                                    onclick: move |_| moving.set(None),
+                                }
+                            }
+                        } else {
+                            div { class: "grid grid-cols-2 gap-2",
+                                Button {
+                                    value: "Color",
+                                    color: Some("bg-slate-600 hover:bg-slate-700".to_string()),
+                                    disabled: selected().is_none(),
+                                    onclick: move |_| {
+                                        if let Some(node) = selected() {
+                                            sandbox.edit(|tree| tree.toggle_color(node));
+                                        }
+                                    },
+                                }
+                                Button {
+                                    value: "Move subtree",
+                                    color: Some("bg-amber-500 hover:bg-amber-600".to_string()),
+                                    disabled: selected().is_none(),
+                                    onclick: move |_| {
+                                        moving.set(selected());
+                                        selected.set(None);
+                                    },
+                                }
+                                Button {
+                                    value: "⟲ Left",
+                                    color: Some("bg-blue-500 hover:bg-blue-600".to_string()),
+                                    disabled: selected().is_none(),
+                                    onclick: move |_| {
+                                        if let Some(node) = selected() {
+                                            sandbox.edit(|tree| tree.rotate_left(node));
+                                        }
+                                    },
+                                }
+                                Button {
+                                    value: "Right ⟳",
+                                    color: Some("bg-blue-500 hover:bg-blue-600".to_string()),
+                                    disabled: selected().is_none(),
+                                    onclick: move |_| {
+                                        if let Some(node) = selected() {
+                                            sandbox.edit(|tree| tree.rotate_right(node));
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                        if let Some(message) = sandbox.message.read().clone() {
+                            p { class: "text-xs text-red-600", role: "alert", "{message}" }
+                        }
+                    }
+
+                    div { class: "grid grid-cols-2 gap-2 mt-auto",
+                        Button {
+                            value: "Undo",
+                            color: Some("bg-slate-500 hover:bg-slate-600".to_string()),
+                            disabled: sandbox.undo.read().is_empty(),
+                            onclick: move |_| {
+                                selected.set(None);
+                                moving.set(None);
+                                sandbox.undo();
+                            },
+                        }
+                        Button {
+                            value: "Clear",
+                            color: Some("bg-slate-500 hover:bg-slate-600".to_string()),
+                            onclick: move |_| {
+                                selected.set(None);
+                                moving.set(None);
+                                sandbox.load(RBTree::new());
+                            },
+                        }
+                        Button {
+                            value: "Random tree",
+                            color: Some("bg-indigo-500 hover:bg-indigo-600".to_string()),
+                            onclick: move |_| {
+                                selected.set(None);
+                                moving.set(None);
+                                sandbox.load(random_tree());
+                            },
+                        }
+                        Button {
+                            value: "From visualizer",
+                            color: Some("bg-indigo-500 hover:bg-indigo-600".to_string()),
+                            onclick: move |_| {
+                                selected.set(None);
+                                moving.set(None);
+                                sandbox.load(RBTREE.peek().snapshot());
+                            },
+                        }
+                    }
+                }
+
+                div { class: "flex flex-col gap-1 flex-1 min-w-0",
+                    div { class: "flex flex-col flex-1 min-h-0 rounded-xl bg-gradient-to-br from-slate-50 to-slate-100 shadow-lg p-4 border border-slate-200",
+                        TreeView {
+                            tree: board,
+                            selected: selected(),
+                            marked,
+                            onselect: move |node| {
+                                if moving() != Some(node) {
+                                    selected.set(Some(node));
+                                }
+                            },
+                        }
+                    }
+                    div {
+                        class: if violations.is_empty() { "rounded-xl shadow-lg p-4 border border-emerald-300 bg-emerald-50 text-sm text-slate-700" } else { "rounded-xl shadow-lg p-4 border border-red-300 bg-red-50 text-sm text-slate-700" },
+                        if violations.is_empty() {
+                            p { class: "font-semibold", "✓ This is a valid red-black tree" }
+                        } else {
+                            p { class: "font-semibold mb-2", "✗ Broken rules" }
+                            ul { class: "flex flex-col gap-1 list-disc pl-5",
+                                for violation in violations.iter() {
+                                    li { "{describe(&board.read(), *violation)}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
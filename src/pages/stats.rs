@@ -0,0 +1,131 @@
+use dioxus::prelude::*;
+use rbt_core::stats::{OperationStats, Totals, TreeStats};
+
+use crate::components::chart::{LineChart, Series};
+use crate::history;
+use crate::store::{RBTREE, STATS};
+
+fn series(
+    label: &'static str,
+    color: &'static str,
+    stats: &[OperationStats],
+    value: impl Fn(&OperationStats) -> f64,
+) -> Series {
+    Series {
+        label,
+        color,
+        values: stats.iter().map(value).collect(),
+        dashed: false,
+    }
+}
+
+#[component]
+fn Card(label: &'static str, value: String) -> Element {
+    rsx! {
+        div { class: "flex flex-col bg-white rounded-xl px-4 py-3 border border-slate-200 shadow",
+            span { class: "text-xs font-medium text-slate-500", "{label}" }
+            span { class: "text-2xl font-semibold text-slate-700", "{value}" }
+        }
+    }
+}
+
+/// Statistics for the session, read off the traces of every recorded operation.
+#[component]
+pub fn Stats() -> Element {
+    let stats = STATS.read();
+    let totals = Totals::of(&stats);
+    let shown = TreeStats::of(&RBTREE.read());
+
+    let height = vec![
+        series("Height", "#2563eb", &stats, |s| f64::from(s.after.height)),
+        Series {
+            dashed: true,
+            ..series("2·log₂(n+1)", "#94a3b8", &stats, |s| {
+                s.after.height_bound()
+            })
+        },
+        series("Black height", "#0f172a", &stats, |s| {
+            f64::from(s.after.black_height)
+        }),
+    ];
+    let colors = vec![
+        series("Nodes", "#64748b", &stats, |s| s.after.nodes as f64),
+        series("Red nodes", "#dc2626", &stats, |s| s.after.red_nodes as f64),
+    ];
+    let work = vec![
+        series("Comparisons", "#2563eb", &stats, |s| s.comparisons as f64),
+        series("Rotations", "#d97706", &stats, |s| s.rotations as f64),
+        series("Recolorings", "#dc2626", &stats, |s| s.recolorings as f64),
+    ];
+
+    rsx! {
+        div { class: "flex flex-col justify-end items-center h-screen",
+            div { class: "flex flex-col gap-4 h-[calc(100vh-3.5rem)] w-full p-6 overflow-y-auto bg-gradient-to-br from-slate-50 to-slate-100",
+                div { class: "flex items-center gap-2",
+                    i { class: "fas fa-chart-line text-blue-500 text-xl" }
+                    h2 { class: "text-xl font-semibold text-slate-700", "Statistics" }
+                }
+
+                h3 { class: "text-sm font-semibold text-slate-600", "Tree on the canvas" }
+                div { class: "grid grid-cols-4 max-sm:grid-cols-2 gap-2",
+                    Card { label: "Nodes", value: shown.nodes.to_string() }
+                    Card {
+                        label: "Height / 2·log₂(n+1)",
+                        value: format!("{} / {:.1}", shown.height, shown.height_bound()),
+                    }
+                    Card { label: "Black height", value: shown.black_height.to_string() }
+                    Card { label: "Red nodes", value: shown.red_nodes.to_string() }
+                }
+
+                h3 { class: "text-sm font-semibold text-slate-600", "Session totals" }
+                div { class: "grid grid-cols-4 max-sm:grid-cols-2 gap-2",
+                    Card { label: "Operations", value: totals.operations.to_string() }
+                    Card { label: "Comparisons", value: totals.comparisons.to_string() }
+                    Card { label: "Rotations", value: totals.rotations.to_string() }
+                    Card { label: "Recolorings", value: totals.recolorings.to_string() }
+                }
+
+                if stats.is_empty() {
+                    p { class: "text-sm text-slate-500",
+                        "Insert, delete or search for keys in the "
+                        a { class: "text-blue-600 hover:underline", href: "/", "visualizer" }
+                        " and the charts will fill in, one point per operation."
+                    }
+                } else {
+                    div { class: "grid grid-cols-3 max-lg:grid-cols-1 gap-2",
+                        LineChart { title: "Height after each operation", series: height }
+                        LineChart { title: "Red nodes", series: colors }
+                        LineChart { title: "Work per operation", series: work }
+                    }
+
+                    div { class: "bg-white rounded-xl border border-slate-200 shadow overflow-x-auto",
+                        table { class: "w-full text-sm text-slate-600",
+                            thead { class: "text-xs text-slate-500 bg-slate-50",
+                                tr {
+                                    for heading in ["#", "Operation", "Comparisons", "Rotations", "Recolorings", "Height", "Bound", "Black height", "Red nodes"] {
+                                        th { class: "px-3 py-2 text-left font-medium", "{heading}" }
+                                    }
+                                }
+                            }
+                            tbody {
+                                for (i , s) in stats.iter().enumerate() {
+                                    tr { key: "{i}", class: "border-t border-slate-100",
+                                        td { class: "px-3 py-1 text-slate-400", "{i + 1}" }
+                                        td { class: "px-3 py-1 font-medium", "{history::describe(s.operation)}" }
+                                        td { class: "px-3 py-1", "{s.comparisons}" }
+                                        td { class: "px-3 py-1", "{s.rotations}" }
+                                        td { class: "px-3 py-1", "{s.recolorings}" }
+                                        td { class: "px-3 py-1", "{s.after.height}" }
+                                        td { class: "px-3 py-1", "{s.after.height_bound():.1}" }
+                                        td { class: "px-3 py-1", "{s.after.black_height}" }
+                                        td { class: "px-3 py-1", "{s.after.red_nodes}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
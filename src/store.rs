@@ -1,5 +1,10 @@
-use crate::algorithm::tree::RBTree;
 use dioxus::prelude::*;
+use rbt_core::pseudocode::Highlight;
+use rbt_core::scene::SceneOptions;
+use rbt_core::stats::OperationStats;
+use rbt_core::trace::Operation;
+use rbt_core::tree::{Duplicates, Pointer, RBTree};
+use rbt_core::viewport::ViewBox;
 
 #[derive(Debug, Clone)]
 pub struct Controls {
@@ -7,15 +12,35 @@ pub struct Controls {
     pub speed: Signal<i32>,
 }
 
+/// One recorded step of `operation`, with its pseudocode line and a sentence describing it.
+#[derive(Debug, Clone)]
+pub struct TreeState {
+    pub operation: Operation,
+    /// Position of this step within its operation.
+    pub step: usize,
+    pub tree: RBTree,
+    pub highlight: Highlight,
+    pub narration: String,
+}
+
 pub static SELECTED_TREE: GlobalSignal<String> = Signal::global(|| "Red-Black Tree".to_string());
-pub static RED_BLACK_TREE: GlobalSignal<RBTree> = Signal::global(|| RBTree::new());
-pub static RBTREE: GlobalSignal<RBTree> = Signal::global(|| RBTree::new());
+pub static RED_BLACK_TREE: GlobalSignal<RBTree> = Signal::global(RBTree::new);
+pub static RBTREE: GlobalSignal<RBTree> = Signal::global(RBTree::new);
 pub static CONTROLS: GlobalSignal<Controls> = Signal::global(|| Controls {
     ind: Signal::new(0),
     speed: Signal::new(0),
 });
 
-pub static TREE_STATES: GlobalSignal<Vec<RBTree>> = Signal::global(Vec::new);
-pub static SVG_VIEW_BOX: GlobalSignal<Vec<f32>> =
-    Signal::global(|| vec![-50.0, -20.0, 300.0, 300.0]);
+pub static TREE_STATES: GlobalSignal<Vec<TreeState>> = Signal::global(Vec::new);
+/// One entry per recorded operation, in the order they were applied.
+pub static STATS: GlobalSignal<Vec<OperationStats>> = Signal::global(Vec::new);
+pub static DUPLICATES: GlobalSignal<Duplicates> = Signal::global(Duplicates::default);
+pub static SVG_VIEW_BOX: GlobalSignal<ViewBox> = Signal::global(ViewBox::default);
+pub static AUTO_FIT: GlobalSignal<bool> = Signal::global(|| true);
+pub static SCENE_OPTIONS: GlobalSignal<SceneOptions> = Signal::global(SceneOptions::default);
+pub static SELECTED_NODE: GlobalSignal<Option<Pointer>> = Signal::global(|| None);
+pub static SEARCH_PATH: GlobalSignal<Vec<Pointer>> = Signal::global(Vec::new);
+pub static TRAVERSAL: GlobalSignal<Vec<Pointer>> = Signal::global(Vec::new);
+pub static PLAYING: GlobalSignal<bool> = Signal::global(|| false);
+pub static SHORTCUTS_OPEN: GlobalSignal<bool> = Signal::global(|| false);
 pub static STATUS: GlobalSignal<String> = Signal::global(|| "IDLE".to_string());